@@ -0,0 +1,63 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use koyomi::{Calendar, Date, HolidayTable};
+
+/// 2000年〜2029年の30年分の日付
+fn dates() -> Vec<Date> {
+    Calendar::build()
+        .from("2000-01")
+        .until("2029-12")
+        .finalize()
+        .unwrap()
+        .make()
+}
+
+fn single(c: &mut Criterion) {
+    let dates = dates();
+    let table = HolidayTable::new();
+    table.preload(2000..2030);
+
+    let mut group = c.benchmark_group("single");
+    group.bench_function("rules", |b| {
+        b.iter(|| {
+            dates
+                .iter()
+                .filter(|d| koyomi::holiday(black_box(d)).is_some())
+                .count()
+        })
+    });
+    group.bench_function("table", |b| {
+        b.iter(|| {
+            dates
+                .iter()
+                .filter(|d| table.is_holiday(black_box(d)))
+                .count()
+        })
+    });
+    group.finish();
+}
+
+fn batch(c: &mut Criterion) {
+    let dates = dates();
+    let table = HolidayTable::new();
+    table.preload(2000..2030);
+
+    let mut group = c.benchmark_group("batch");
+    group.bench_function("rules", |b| {
+        b.iter(|| {
+            black_box(&dates)
+                .iter()
+                .map(|d| d.is_business_day())
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("table", |b| {
+        b.iter(|| table.business_days(black_box(&dates)))
+    });
+    group.bench_function("table (cold)", |b| {
+        b.iter(|| HolidayTable::new().business_days(black_box(&dates)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, single, batch);
+criterion_main!(benches);
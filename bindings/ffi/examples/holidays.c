@@ -0,0 +1,59 @@
+/*
+ * 指定年の祝祭日と、指定日の和暦・祝祭日を表示する。
+ *
+ *   cargo build --release -p koyomi-ffi
+ *   cc -I include examples/holidays.c ../../target/release/libkoyomi_ffi.a \
+ *      -lpthread -ldl -lm -o holidays
+ *   ./holidays 2019
+ */
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "koyomi.h"
+
+static void print_era(KoyomiDate date) {
+  char name[16];
+  int32_t year;
+  KoyomiStatus status =
+      koyomi_era(date, name, sizeof(name), NULL, &year);
+  if (status == KOYOMI_STATUS_OK) {
+    printf("%s%d年", name, year);
+  } else {
+    printf("(和暦なし)");
+  }
+}
+
+int main(int argc, char **argv) {
+  int32_t year = argc > 1 ? atoi(argv[1]) : 2019;
+
+  KoyomiHolidayList *list = koyomi_holidays(year);
+  if (list == NULL) {
+    fprintf(stderr, "%d年は扱える範囲外です\n", year);
+    return 1;
+  }
+
+  for (size_t i = 0; i < koyomi_holiday_list_len(list); i++) {
+    KoyomiDate date;
+    const char *name;
+    koyomi_holiday_list_get(list, i, &date, &name);
+    printf("%04d-%02u-%02u ", date.year, date.month, date.day);
+    print_era(date);
+    printf(" %s\n", name);
+  }
+  koyomi_holiday_list_free(list);
+
+  /* 必要なバイト数を問い合わせてから、名前を取得する */
+  KoyomiDate date = {year, 5, 3};
+  size_t required = 0;
+  if (koyomi_holiday(date, NULL, 0, &required) == KOYOMI_STATUS_BUFFER_TOO_SMALL) {
+    char *buf = malloc(required);
+    koyomi_holiday(date, buf, required, NULL);
+    printf("5月3日は%s\n", buf);
+    free(buf);
+  }
+
+  bool business;
+  koyomi_is_business_day(date, &business);
+  printf("5月3日は営業日%s\n", business ? "です" : "ではありません");
+  return 0;
+}
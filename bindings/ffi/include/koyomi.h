@@ -0,0 +1,125 @@
+/* koyomi の C ABI。このファイルは cbindgen で生成している。直接編集しないこと。 */
+
+#ifndef KOYOMI_H
+#define KOYOMI_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+// 処理結果
+typedef enum KoyomiStatus {
+  // 成功
+  KOYOMI_STATUS_OK = 0,
+  // 該当なし(祝祭日ではない・和暦がない)
+  KOYOMI_STATUS_NOT_FOUND = 1,
+  // 存在しない日付
+  KOYOMI_STATUS_INVALID_DATE = -1,
+  // バッファが不足している(必要なバイト数は `required` に書き込む)
+  KOYOMI_STATUS_BUFFER_TOO_SMALL = -2,
+  // 必須のポインタが NULL
+  KOYOMI_STATUS_NULL_POINTER = -3,
+  // 範囲外の添字
+  KOYOMI_STATUS_OUT_OF_BOUNDS = -4,
+} KoyomiStatus;
+
+// 祝祭日の一覧
+//
+// 中身は公開しない。`koyomi_holiday_list_*` で参照する。
+typedef struct KoyomiHolidayList KoyomiHolidayList;
+
+// 日付
+typedef struct KoyomiDate {
+  int32_t year;
+  uint32_t month;
+  uint32_t day;
+} KoyomiDate;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// 祝祭日であれば、その名前をバッファに書き込む
+//
+// 祝祭日でなければ `NotFound` を返す。
+// `required` には NUL を含めた必要なバイト数を書き込む(NULL であれば書き込まない)。
+// `buf_len` が 0 であれば `buf` は NULL でもよく、必要なバイト数の取得にのみ使える。
+//
+// # Safety
+//
+// `buf` は `buf_len` バイト書き込み可能な領域、`required` は NULL または有効なポインタであること。
+enum KoyomiStatus koyomi_holiday(struct KoyomiDate date,
+                                 char *buf,
+                                 size_t buf_len,
+                                 size_t *required);
+
+// 和暦があれば、元号名をバッファに、和暦の年を `era_year` に書き込む
+//
+// 明治以前は `NotFound` を返す。
+// バッファの扱いは `koyomi_holiday` と同じ。
+//
+// # Safety
+//
+// `koyomi_holiday` の条件に加えて、`era_year` は NULL または有効なポインタであること。
+enum KoyomiStatus koyomi_era(struct KoyomiDate date,
+                             char *buf,
+                             size_t buf_len,
+                             size_t *required,
+                             int32_t *era_year);
+
+// 祝祭日かどうかを `result` に書き込む
+//
+// # Safety
+//
+// `result` は有効なポインタであること。
+enum KoyomiStatus koyomi_is_holiday(struct KoyomiDate date, bool *result);
+
+// 営業日(土曜・日曜・祝祭日以外)かどうかを `result` に書き込む
+//
+// # Safety
+//
+// `result` は有効なポインタであること。
+enum KoyomiStatus koyomi_is_business_day(struct KoyomiDate date, bool *result);
+
+// 指定年の祝祭日の一覧を生成する
+//
+// 年が扱える範囲外であれば NULL を返す。
+// 返した一覧は `koyomi_holiday_list_free` で解放すること。
+struct KoyomiHolidayList *koyomi_holidays(int32_t year);
+
+// 一覧に含まれる祝祭日の数を返す
+//
+// # Safety
+//
+// `list` は NULL または `koyomi_holidays` が返した未解放のポインタであること。
+size_t koyomi_holiday_list_len(const struct KoyomiHolidayList *list);
+
+// 一覧の `index` 番目の祝祭日の日付と名前を書き込む
+//
+// `name` に書き込むポインタは一覧が所有し、一覧を解放するまで有効となる。
+// `date`・`name` のいずれも NULL であれば書き込まない。
+//
+// # Safety
+//
+// `list` は `koyomi_holidays` が返した未解放のポインタ、
+// `date`・`name` は NULL または有効なポインタであること。
+enum KoyomiStatus koyomi_holiday_list_get(const struct KoyomiHolidayList *list,
+                                          size_t index,
+                                          struct KoyomiDate *date,
+                                          const char **name);
+
+// 一覧を解放する
+//
+// NULL を渡した場合は何もしない。
+//
+// # Safety
+//
+// `list` は NULL または `koyomi_holidays` が返した未解放のポインタであること。
+// 解放後は一覧と、一覧から取得した名前を参照しないこと。
+void koyomi_holiday_list_free(struct KoyomiHolidayList *list);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* KOYOMI_H */
@@ -0,0 +1,391 @@
+//! # koyomi-ffi
+//!
+//! koyomi を C ABI で公開する。
+//! ヘッダは `include/koyomi.h`(cbindgen で生成)を使う。
+//!
+//! ## 所有権
+//!
+//! - 日付は `KoyomiDate` の値渡しとし、ポインタを保持しない
+//! - 名前などの文字列は呼び出し側が用意したバッファに NUL 終端の UTF-8 で書き込む
+//! - `koyomi_holidays` が返す一覧はライブラリが所有し、`koyomi_holiday_list_free` で解放する
+//!   一覧から取得した名前のポインタは、一覧を解放するまで有効となる
+use std::ffi::CString;
+use std::os::raw::c_char;
+use std::ptr;
+use std::slice;
+
+use koyomi::Date;
+
+/// 日付
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct KoyomiDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+/// 処理結果
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KoyomiStatus {
+    /// 成功
+    Ok = 0,
+    /// 該当なし(祝祭日ではない・和暦がない)
+    NotFound = 1,
+    /// 存在しない日付
+    InvalidDate = -1,
+    /// バッファが不足している(必要なバイト数は `required` に書き込む)
+    BufferTooSmall = -2,
+    /// 必須のポインタが NULL
+    NullPointer = -3,
+    /// 範囲外の添字
+    OutOfBounds = -4,
+}
+
+/// 祝祭日の一覧
+///
+/// 中身は公開しない。`koyomi_holiday_list_*` で参照する。
+pub struct KoyomiHolidayList {
+    items: Vec<(KoyomiDate, CString)>,
+}
+
+/// 祝祭日であれば、その名前をバッファに書き込む
+///
+/// 祝祭日でなければ `NotFound` を返す。
+/// `required` には NUL を含めた必要なバイト数を書き込む(NULL であれば書き込まない)。
+/// `buf_len` が 0 であれば `buf` は NULL でもよく、必要なバイト数の取得にのみ使える。
+///
+/// # Safety
+///
+/// `buf` は `buf_len` バイト書き込み可能な領域、`required` は NULL または有効なポインタであること。
+#[no_mangle]
+pub unsafe extern "C" fn koyomi_holiday(
+    date: KoyomiDate,
+    buf: *mut c_char,
+    buf_len: usize,
+    required: *mut usize,
+) -> KoyomiStatus {
+    match to_date(date) {
+        Some(d) => match d.holiday() {
+            Some(name) => write_str(&name, buf, buf_len, required),
+            None => KoyomiStatus::NotFound,
+        },
+        None => KoyomiStatus::InvalidDate,
+    }
+}
+
+/// 和暦があれば、元号名をバッファに、和暦の年を `era_year` に書き込む
+///
+/// 明治以前は `NotFound` を返す。
+/// バッファの扱いは `koyomi_holiday` と同じ。
+///
+/// # Safety
+///
+/// `koyomi_holiday` の条件に加えて、`era_year` は NULL または有効なポインタであること。
+#[no_mangle]
+pub unsafe extern "C" fn koyomi_era(
+    date: KoyomiDate,
+    buf: *mut c_char,
+    buf_len: usize,
+    required: *mut usize,
+    era_year: *mut i32,
+) -> KoyomiStatus {
+    let era = match to_date(date) {
+        Some(d) => d.era(),
+        None => return KoyomiStatus::InvalidDate,
+    };
+    match era {
+        Some(era) => {
+            if !era_year.is_null() {
+                *era_year = era.year();
+            }
+            write_str(&era.name(), buf, buf_len, required)
+        }
+        None => KoyomiStatus::NotFound,
+    }
+}
+
+/// 祝祭日かどうかを `result` に書き込む
+///
+/// # Safety
+///
+/// `result` は有効なポインタであること。
+#[no_mangle]
+pub unsafe extern "C" fn koyomi_is_holiday(date: KoyomiDate, result: *mut bool) -> KoyomiStatus {
+    judge(date, result, |d| d.holiday().is_some())
+}
+
+/// 営業日(土曜・日曜・祝祭日以外)かどうかを `result` に書き込む
+///
+/// # Safety
+///
+/// `result` は有効なポインタであること。
+#[no_mangle]
+pub unsafe extern "C" fn koyomi_is_business_day(
+    date: KoyomiDate,
+    result: *mut bool,
+) -> KoyomiStatus {
+    judge(date, result, Date::is_business_day)
+}
+
+/// 指定年の祝祭日の一覧を生成する
+///
+/// 年が扱える範囲外であれば NULL を返す。
+/// 返した一覧は `koyomi_holiday_list_free` で解放すること。
+#[no_mangle]
+pub extern "C" fn koyomi_holidays(year: i32) -> *mut KoyomiHolidayList {
+    let holidays = match koyomi::holidays(year) {
+        Ok(holidays) => holidays,
+        Err(_) => return ptr::null_mut(),
+    };
+    let items = holidays
+        .into_iter()
+        .map(|(d, name)| {
+            let name = CString::new(name).expect("Holiday name contains NUL!");
+            (from_date(&d), name)
+        })
+        .collect();
+    Box::into_raw(Box::new(KoyomiHolidayList { items }))
+}
+
+/// 一覧に含まれる祝祭日の数を返す
+///
+/// # Safety
+///
+/// `list` は NULL または `koyomi_holidays` が返した未解放のポインタであること。
+#[no_mangle]
+pub unsafe extern "C" fn koyomi_holiday_list_len(list: *const KoyomiHolidayList) -> usize {
+    list.as_ref().map_or(0, |l| l.items.len())
+}
+
+/// 一覧の `index` 番目の祝祭日の日付と名前を書き込む
+///
+/// `name` に書き込むポインタは一覧が所有し、一覧を解放するまで有効となる。
+/// `date`・`name` のいずれも NULL であれば書き込まない。
+///
+/// # Safety
+///
+/// `list` は `koyomi_holidays` が返した未解放のポインタ、
+/// `date`・`name` は NULL または有効なポインタであること。
+#[no_mangle]
+pub unsafe extern "C" fn koyomi_holiday_list_get(
+    list: *const KoyomiHolidayList,
+    index: usize,
+    date: *mut KoyomiDate,
+    name: *mut *const c_char,
+) -> KoyomiStatus {
+    let list = match list.as_ref() {
+        Some(list) => list,
+        None => return KoyomiStatus::NullPointer,
+    };
+    let (d, n) = match list.items.get(index) {
+        Some(item) => item,
+        None => return KoyomiStatus::OutOfBounds,
+    };
+    if !date.is_null() {
+        *date = *d;
+    }
+    if !name.is_null() {
+        *name = n.as_ptr();
+    }
+    KoyomiStatus::Ok
+}
+
+/// 一覧を解放する
+///
+/// NULL を渡した場合は何もしない。
+///
+/// # Safety
+///
+/// `list` は NULL または `koyomi_holidays` が返した未解放のポインタであること。
+/// 解放後は一覧と、一覧から取得した名前を参照しないこと。
+#[no_mangle]
+pub unsafe extern "C" fn koyomi_holiday_list_free(list: *mut KoyomiHolidayList) {
+    if !list.is_null() {
+        drop(Box::from_raw(list));
+    }
+}
+
+/// `KoyomiDate` から日付を生成する
+fn to_date(date: KoyomiDate) -> Option<Date> {
+    Date::from_ymd(date.year, date.month, date.day).ok()
+}
+
+/// 日付から `KoyomiDate` を生成する
+fn from_date(date: &Date) -> KoyomiDate {
+    KoyomiDate {
+        year: date.year(),
+        month: date.month(),
+        day: date.day(),
+    }
+}
+
+/// 日付を判定して `result` に書き込む
+unsafe fn judge<F: Fn(&Date) -> bool>(date: KoyomiDate, result: *mut bool, f: F) -> KoyomiStatus {
+    if result.is_null() {
+        return KoyomiStatus::NullPointer;
+    }
+    match to_date(date) {
+        Some(d) => {
+            *result = f(&d);
+            KoyomiStatus::Ok
+        }
+        None => KoyomiStatus::InvalidDate,
+    }
+}
+
+/// 文字列を NUL 終端でバッファに書き込む
+unsafe fn write_str(
+    s: &str,
+    buf: *mut c_char,
+    buf_len: usize,
+    required: *mut usize,
+) -> KoyomiStatus {
+    let len = s.len() + 1;
+    if !required.is_null() {
+        *required = len;
+    }
+    if buf_len < len {
+        return KoyomiStatus::BufferTooSmall;
+    }
+    if buf.is_null() {
+        return KoyomiStatus::NullPointer;
+    }
+
+    let out = slice::from_raw_parts_mut(buf as *mut u8, len);
+    out[..s.len()].copy_from_slice(s.as_bytes());
+    out[s.len()] = 0;
+    KoyomiStatus::Ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    fn date(year: i32, month: u32, day: u32) -> KoyomiDate {
+        KoyomiDate { year, month, day }
+    }
+
+    #[test]
+    fn holiday_into_buffer() {
+        let mut buf = [0 as c_char; 32];
+        let mut required = 0;
+        let status =
+            unsafe { koyomi_holiday(date(2018, 1, 1), buf.as_mut_ptr(), buf.len(), &mut required) };
+        assert_eq!(status, KoyomiStatus::Ok);
+        assert_eq!(required, "元日".len() + 1);
+        let name = unsafe { CStr::from_ptr(buf.as_ptr()) };
+        assert_eq!(name.to_str().unwrap(), "元日");
+
+        let status =
+            unsafe { koyomi_holiday(date(2018, 1, 2), buf.as_mut_ptr(), buf.len(), &mut required) };
+        assert_eq!(status, KoyomiStatus::NotFound);
+        let status = unsafe {
+            koyomi_holiday(
+                date(2018, 2, 30),
+                buf.as_mut_ptr(),
+                buf.len(),
+                &mut required,
+            )
+        };
+        assert_eq!(status, KoyomiStatus::InvalidDate);
+    }
+
+    #[test]
+    fn query_required_size() {
+        let mut required = 0;
+        let status =
+            unsafe { koyomi_holiday(date(2018, 11, 23), ptr::null_mut(), 0, &mut required) };
+        assert_eq!(status, KoyomiStatus::BufferTooSmall);
+        assert_eq!(required, "勤労感謝の日".len() + 1);
+
+        let mut buf = vec![0 as c_char; required - 1];
+        let status = unsafe {
+            koyomi_holiday(
+                date(2018, 11, 23),
+                buf.as_mut_ptr(),
+                buf.len(),
+                ptr::null_mut(),
+            )
+        };
+        assert_eq!(status, KoyomiStatus::BufferTooSmall);
+        assert!(buf.iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn era_name_and_year() {
+        let mut buf = [0 as c_char; 16];
+        let mut year = 0;
+        let status = unsafe {
+            koyomi_era(
+                date(2019, 5, 1),
+                buf.as_mut_ptr(),
+                buf.len(),
+                ptr::null_mut(),
+                &mut year,
+            )
+        };
+        assert_eq!(status, KoyomiStatus::Ok);
+        assert_eq!(
+            unsafe { CStr::from_ptr(buf.as_ptr()) }.to_str().unwrap(),
+            "令和"
+        );
+        assert_eq!(year, 1);
+
+        let status = unsafe {
+            koyomi_era(
+                date(1800, 1, 1),
+                buf.as_mut_ptr(),
+                buf.len(),
+                ptr::null_mut(),
+                &mut year,
+            )
+        };
+        assert_eq!(status, KoyomiStatus::NotFound);
+    }
+
+    #[test]
+    fn business_day() {
+        let mut result = true;
+        let status = unsafe { koyomi_is_business_day(date(2018, 1, 8), &mut result) };
+        assert_eq!(status, KoyomiStatus::Ok);
+        assert!(!result);
+
+        let status = unsafe { koyomi_is_business_day(date(2018, 1, 9), &mut result) };
+        assert_eq!(status, KoyomiStatus::Ok);
+        assert!(result);
+
+        let status = unsafe { koyomi_is_holiday(date(2018, 1, 8), &mut result) };
+        assert_eq!(status, KoyomiStatus::Ok);
+        assert!(result);
+
+        let status = unsafe { koyomi_is_holiday(date(2018, 1, 8), ptr::null_mut()) };
+        assert_eq!(status, KoyomiStatus::NullPointer);
+    }
+
+    #[test]
+    fn list_of_year() {
+        let list = koyomi_holidays(2018);
+        assert!(!list.is_null());
+        unsafe {
+            assert_eq!(koyomi_holiday_list_len(list), 20);
+
+            let mut d = KoyomiDate::default();
+            let mut name = ptr::null();
+            let status = koyomi_holiday_list_get(list, 0, &mut d, &mut name);
+            assert_eq!(status, KoyomiStatus::Ok);
+            assert_eq!(d, date(2018, 1, 1));
+            assert_eq!(CStr::from_ptr(name).to_str().unwrap(), "元日");
+
+            let status = koyomi_holiday_list_get(list, 20, &mut d, &mut name);
+            assert_eq!(status, KoyomiStatus::OutOfBounds);
+
+            koyomi_holiday_list_free(list);
+            koyomi_holiday_list_free(ptr::null_mut());
+            assert_eq!(koyomi_holiday_list_len(ptr::null()), 0);
+        }
+        assert!(koyomi_holidays(i32::MAX).is_null());
+    }
+}
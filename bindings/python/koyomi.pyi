@@ -0,0 +1,46 @@
+import datetime
+from typing import Iterable, List, Optional, Tuple, Union
+
+DateLike = Union[datetime.date, "Date", str]
+
+class Date:
+    def __init__(self, year: int, month: int, day: int) -> None: ...
+    @staticmethod
+    def parse(text: str) -> Date: ...
+    @staticmethod
+    def from_date(date: datetime.date) -> Date: ...
+    def to_date(self) -> datetime.date: ...
+    @property
+    def year(self) -> int: ...
+    @property
+    def month(self) -> int: ...
+    @property
+    def day(self) -> int: ...
+    def weekday(self) -> int: ...
+    @property
+    def weekday_japanese(self) -> str: ...
+    def era(self) -> Optional[Era]: ...
+    def holiday(self) -> Optional[str]: ...
+    def is_holiday(self) -> bool: ...
+    def is_business_day(self) -> bool: ...
+    def __eq__(self, other: object) -> bool: ...
+    def __lt__(self, other: Date) -> bool: ...
+    def __le__(self, other: Date) -> bool: ...
+    def __gt__(self, other: Date) -> bool: ...
+    def __ge__(self, other: Date) -> bool: ...
+    def __hash__(self) -> int: ...
+
+class Era:
+    @property
+    def name(self) -> str: ...
+    @property
+    def year(self) -> int: ...
+    def format(self) -> str: ...
+
+def holiday(date: DateLike) -> Optional[str]: ...
+def era(date: DateLike) -> Optional[Era]: ...
+def is_holiday(date: DateLike) -> bool: ...
+def is_business_day(date: DateLike) -> bool: ...
+def holidays(year: int) -> List[Tuple[datetime.date, str]]: ...
+def holiday_names(dates: Iterable[DateLike]) -> List[Optional[str]]: ...
+def business_days(dates: Iterable[DateLike]) -> List[bool]: ...
@@ -0,0 +1,433 @@
+//! # koyomi-python
+//!
+//! koyomi を Python から使うための PyO3 バインディング。
+//! `maturin build` で、`koyomi` モジュールを含む wheel を生成する。
+//!
+//! 日付を受け取る関数は `datetime.date`(`datetime.datetime` や `pandas.Timestamp` を含む)、
+//! `koyomi.Date`、`YYYY-MM-DD` 形式の文字列のいずれも受け付ける。
+//! 日付を返す関数は `datetime.date` を返す。
+//! 曜日の番号は `datetime.date.weekday()` と同じく月曜を0とする。
+use std::sync::OnceLock;
+
+use koyomi::{HolidayTable, KoyomiError};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyDate, PyDateAccess, PyString};
+
+/// 日付
+#[pyclass(frozen, eq, ord, hash, skip_from_py_object, module = "koyomi")]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Date {
+    inner: koyomi::Date,
+}
+
+#[pymethods]
+impl Date {
+    /// 年月日から生成する
+    #[new]
+    fn new(year: i32, month: u32, day: u32) -> PyResult<Self> {
+        koyomi::Date::from_ymd(year, month, day)
+            .map(Date::from)
+            .map_err(value_error)
+    }
+
+    /// 文字列から生成する
+    /// `YYYY-MM-DD`・`YYYY/MM/DD`・`YYYYMMDD`・ISO 8601 の週日付(`YYYY-Www-D`)のほか、
+    /// `2025年5月1日(木)`・`令和7年5月1日` のような漢字・和暦の日付も受け付ける
+    /// 全角数字も受け付ける
+    #[staticmethod]
+    fn parse(text: &str) -> PyResult<Self> {
+        koyomi::Date::parse(text)
+            .map(Date::from)
+            .map_err(value_error)
+    }
+
+    /// `datetime.date` から生成する
+    #[staticmethod]
+    fn from_date(date: &Bound<'_, PyDate>) -> PyResult<Self> {
+        from_py_date(date).map(Date::from)
+    }
+
+    /// `datetime.date` に変換する
+    #[pyo3(name = "to_date")]
+    fn py_date<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDate>> {
+        to_py_date(py, &self.inner)
+    }
+
+    /// 「年」
+    #[getter]
+    fn year(&self) -> i32 {
+        self.inner.year()
+    }
+
+    /// 「月」(1〜12)
+    #[getter]
+    fn month(&self) -> u32 {
+        self.inner.month()
+    }
+
+    /// 「日」
+    #[getter]
+    fn day(&self) -> u32 {
+        self.inner.day()
+    }
+
+    /// 曜日の番号(月曜を0とする)
+    fn weekday(&self) -> u32 {
+        self.inner.weekday().num_days_from_monday()
+    }
+
+    /// 曜日の日本語表現(「月」など)
+    #[getter]
+    fn weekday_japanese(&self) -> char {
+        self.inner.weekday().japanese()
+    }
+
+    /// 和暦を返す
+    /// 明治以前は `None` となる
+    fn era(&self) -> Option<Era> {
+        self.inner.era().map(Era::from)
+    }
+
+    /// 祝祭日であればその名前を返す
+    fn holiday(&self) -> Option<String> {
+        self.inner.holiday()
+    }
+
+    /// 祝祭日かどうかを判定する
+    fn is_holiday(&self) -> bool {
+        self.inner.holiday().is_some()
+    }
+
+    /// 営業日(土曜・日曜・祝祭日以外)かどうかを判定する
+    fn is_business_day(&self) -> bool {
+        self.inner.is_business_day()
+    }
+
+    fn __str__(&self) -> String {
+        self.inner.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "koyomi.Date({}, {}, {})",
+            self.inner.year(),
+            self.inner.month(),
+            self.inner.day()
+        )
+    }
+}
+
+impl From<koyomi::Date> for Date {
+    fn from(inner: koyomi::Date) -> Self {
+        Date { inner }
+    }
+}
+
+/// 和暦
+#[pyclass(frozen, get_all, skip_from_py_object, module = "koyomi")]
+#[derive(Clone, Debug)]
+pub struct Era {
+    /// 元号名
+    name: String,
+    /// 和暦の年(元年は1)
+    year: i32,
+    format: String,
+}
+
+#[pymethods]
+impl Era {
+    /// 「令和元年」のような表記を返す
+    fn format(&self) -> String {
+        self.format.clone()
+    }
+
+    fn __str__(&self) -> String {
+        self.format.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("koyomi.Era({:?}, {})", self.name, self.year)
+    }
+}
+
+impl From<koyomi::Era> for Era {
+    fn from(era: koyomi::Era) -> Self {
+        Era {
+            name: era.name(),
+            year: era.year(),
+            format: era.format(),
+        }
+    }
+}
+
+/// 日付として受け付ける引数
+struct DateLike(koyomi::Date);
+
+impl<'a, 'py> FromPyObject<'a, 'py> for DateLike {
+    type Error = PyErr;
+
+    fn extract(obj: Borrowed<'a, 'py, PyAny>) -> PyResult<Self> {
+        if let Ok(date) = obj.cast::<Date>() {
+            return Ok(DateLike(date.get().inner));
+        }
+        if let Ok(date) = obj.cast::<PyDate>() {
+            return from_py_date(&date).map(DateLike);
+        }
+        if let Ok(text) = obj.cast::<PyString>() {
+            return koyomi::Date::parse(&text.to_cow()?)
+                .map(DateLike)
+                .map_err(value_error);
+        }
+        Err(PyTypeError::new_err(format!(
+            "日付として扱えません: {}",
+            obj.get_type().name()?
+        )))
+    }
+}
+
+/// 祝祭日であればその名前を返す
+#[pyfunction]
+fn holiday(date: DateLike) -> Option<String> {
+    koyomi::holiday(&date.0)
+}
+
+/// 和暦を返す
+/// 明治以前は `None` となる
+#[pyfunction]
+fn era(date: DateLike) -> Option<Era> {
+    koyomi::era(&date.0).map(Era::from)
+}
+
+/// 祝祭日かどうかを判定する
+#[pyfunction]
+fn is_holiday(date: DateLike) -> bool {
+    table().is_holiday(&date.0)
+}
+
+/// 営業日(土曜・日曜・祝祭日以外)かどうかを判定する
+#[pyfunction]
+fn is_business_day(date: DateLike) -> bool {
+    table().is_business_day(&date.0)
+}
+
+/// 指定年の祝祭日を、日付と名前の組で日付順に返す
+#[pyfunction]
+fn holidays(py: Python<'_>, year: i32) -> PyResult<Vec<(Bound<'_, PyDate>, String)>> {
+    koyomi::holidays(year)
+        .map_err(value_error)?
+        .into_iter()
+        .map(|(date, name)| Ok((to_py_date(py, &date)?, name)))
+        .collect()
+}
+
+/// 複数の日付の祝祭日の名前をまとめて返す
+/// 結果は引数と同じ順に並び、祝祭日でない日は `None` となる
+#[pyfunction]
+fn holiday_names(dates: &Bound<'_, PyAny>) -> PyResult<Vec<Option<String>>> {
+    Ok(table().names(&collect(dates)?))
+}
+
+/// 複数の日付が営業日かどうかをまとめて判定する
+/// 結果は引数と同じ順に並ぶ
+#[pyfunction]
+fn business_days(dates: &Bound<'_, PyAny>) -> PyResult<Vec<bool>> {
+    Ok(table().business_days(&collect(dates)?))
+}
+
+/// 祝祭日を判定する関数の共有テーブル
+fn table() -> &'static HolidayTable {
+    static TABLE: OnceLock<HolidayTable> = OnceLock::new();
+    TABLE.get_or_init(HolidayTable::new)
+}
+
+/// 任意のイテラブルから日付を取り出す
+fn collect(dates: &Bound<'_, PyAny>) -> PyResult<Vec<koyomi::Date>> {
+    dates
+        .try_iter()?
+        .map(|item| item?.extract::<DateLike>().map(|d| d.0))
+        .collect()
+}
+
+/// `datetime.date` から変換する
+fn from_py_date(date: &Bound<'_, PyDate>) -> PyResult<koyomi::Date> {
+    koyomi::Date::from_ymd(
+        date.get_year(),
+        u32::from(date.get_month()),
+        u32::from(date.get_day()),
+    )
+    .map_err(value_error)
+}
+
+/// `datetime.date` に変換する
+/// `datetime.date` で扱えない年は `ValueError` となる
+fn to_py_date<'py>(py: Python<'py>, date: &koyomi::Date) -> PyResult<Bound<'py, PyDate>> {
+    PyDate::new(py, date.year(), date.month() as u8, date.day() as u8)
+}
+
+/// Python の `ValueError` に変換する
+/// メッセージは日本語となる
+fn value_error(err: KoyomiError) -> PyErr {
+    PyValueError::new_err(err.to_string())
+}
+
+/// 日本の暦(祝祭日・和暦・営業日)
+#[pymodule]
+#[pyo3(name = "koyomi")]
+fn koyomi_python(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Date>()?;
+    m.add_class::<Era>()?;
+    m.add_function(wrap_pyfunction!(holiday, m)?)?;
+    m.add_function(wrap_pyfunction!(era, m)?)?;
+    m.add_function(wrap_pyfunction!(is_holiday, m)?)?;
+    m.add_function(wrap_pyfunction!(is_business_day, m)?)?;
+    m.add_function(wrap_pyfunction!(holidays, m)?)?;
+    m.add_function(wrap_pyfunction!(holiday_names, m)?)?;
+    m.add_function(wrap_pyfunction!(business_days, m)?)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+
+    /// モジュールを `koyomi` として読み込んだ状態で、Python の式を評価する
+    fn eval<T, F>(code: &str, f: F) -> T
+    where
+        F: for<'py> FnOnce(PyResult<Bound<'py, PyAny>>) -> T,
+    {
+        Python::attach(|py| {
+            let module = PyModule::new(py, "koyomi").unwrap();
+            koyomi_python(&module).unwrap();
+            let globals = PyDict::new(py);
+            globals.set_item("koyomi", module).unwrap();
+            globals
+                .set_item("datetime", py.import("datetime").unwrap())
+                .unwrap();
+            let code = std::ffi::CString::new(code).unwrap();
+            f(py.eval(&code, Some(&globals), None))
+        })
+    }
+
+    #[test]
+    fn date() {
+        let result = eval(
+            "(lambda d: (d.year, d.month, d.day, d.weekday(), d.weekday_japanese, \
+             d.holiday(), d.era().format(), d.is_business_day(), str(d), repr(d)))\
+             (koyomi.Date(2019, 5, 1))",
+            |r| {
+                r.unwrap()
+                    .extract::<(
+                        i32,
+                        u32,
+                        u32,
+                        u32,
+                        String,
+                        String,
+                        String,
+                        bool,
+                        String,
+                        String,
+                    )>()
+                    .unwrap()
+            },
+        );
+        assert_eq!((result.0, result.1, result.2, result.3), (2019, 5, 1, 2));
+        assert_eq!(result.4, "水");
+        assert_eq!(result.5, "新天皇即位日");
+        assert_eq!(result.6, "令和元年");
+        assert!(!result.7);
+        assert_eq!(result.8, "2019-05-01");
+        assert_eq!(result.9, "koyomi.Date(2019, 5, 1)");
+    }
+
+    #[test]
+    fn convert_datetime() {
+        let roundtrip = eval(
+            "koyomi.Date.from_date(datetime.date(2018, 1, 8)).to_date() == datetime.date(2018, 1, 8)",
+            |r| r.unwrap().extract::<bool>().unwrap(),
+        );
+        assert!(roundtrip);
+
+        let ordered = eval(
+            "sorted([koyomi.Date.parse('2018/12/31'), koyomi.Date(2018, 1, 1)])[0] == koyomi.Date(2018, 1, 1)",
+            |r| r.unwrap().extract::<bool>().unwrap(),
+        );
+        assert!(ordered);
+    }
+
+    #[test]
+    fn accept_date_like() {
+        let names = eval(
+            "[koyomi.holiday(d) for d in (datetime.date(2018, 1, 1), \
+             datetime.datetime(2018, 1, 8, 12, 30), koyomi.Date(2018, 2, 11), '2018-01-02')]",
+            |r| r.unwrap().extract::<Vec<Option<String>>>().unwrap(),
+        );
+        assert_eq!(
+            names,
+            vec![
+                Some("元日".to_string()),
+                Some("成人の日".to_string()),
+                Some("建国記念日".to_string()),
+                None
+            ]
+        );
+
+        let era = eval("koyomi.era('1989-01-08').name", |r| {
+            r.unwrap().extract::<String>().unwrap()
+        });
+        assert_eq!(era, "平成");
+    }
+
+    #[test]
+    fn holidays_of_year() {
+        let (len, first, name) = eval(
+            "(lambda hs: (len(hs), hs[0][0] == datetime.date(2018, 1, 1), hs[0][1]))(koyomi.holidays(2018))",
+            |r| r.unwrap().extract::<(usize, bool, String)>().unwrap(),
+        );
+        assert_eq!(len, 20);
+        assert!(first);
+        assert_eq!(name, "元日");
+    }
+
+    #[test]
+    fn vectorised() {
+        let (names, business) = eval(
+            "(lambda ds: (koyomi.holiday_names(ds), koyomi.business_days(iter(ds))))\
+             ([datetime.date(2018, 1, 1) + datetime.timedelta(days=n) for n in range(8)])",
+            |r| {
+                r.unwrap()
+                    .extract::<(Vec<Option<String>>, Vec<bool>)>()
+                    .unwrap()
+            },
+        );
+        assert_eq!(names.len(), 8);
+        assert_eq!(names[0].as_deref(), Some("元日"));
+        assert_eq!(names.iter().flatten().count(), 2);
+        assert_eq!(
+            business,
+            vec![false, true, true, true, true, false, false, false]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let kinds = [
+            ("koyomi.Date(2018, 2, 29)", "ValueError"),
+            ("koyomi.holiday('2018-13-01')", "ValueError"),
+            ("koyomi.holiday(20180101)", "TypeError"),
+            (
+                "koyomi.business_days([datetime.date(2018, 1, 1), None])",
+                "TypeError",
+            ),
+            ("koyomi.holidays(-300000)", "ValueError"),
+        ];
+        for (code, kind) in kinds.iter() {
+            let name = eval(code, |r| {
+                Python::attach(|py| r.unwrap_err().get_type(py).name().unwrap().to_string())
+            });
+            assert_eq!(name, *kind, "{}", code);
+        }
+    }
+}
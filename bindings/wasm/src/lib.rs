@@ -0,0 +1,370 @@
+//! # koyomi-wasm
+//!
+//! koyomi を JavaScript / TypeScript から使うための WebAssembly バインディング。
+//! `wasm-pack build` で、型定義(`.d.ts`)付きのパッケージを生成する。
+//!
+//! JavaScript の `Date` と区別するため、日付のクラスは `KoyomiDate` として公開する。
+//! 曜日の番号は `Date.prototype.getDay()` と同じく日曜を0とする。
+use koyomi::{KoyomiError, Weekday};
+use wasm_bindgen::prelude::*;
+
+/// 日付
+#[wasm_bindgen(js_name = KoyomiDate)]
+#[derive(Clone, Copy, Debug)]
+pub struct Date {
+    inner: koyomi::Date,
+}
+
+#[wasm_bindgen(js_class = KoyomiDate)]
+impl Date {
+    /// 文字列から生成する
+    /// `YYYY-MM-DD`・`YYYY/MM/DD`・`YYYYMMDD`・ISO 8601 の週日付(`YYYY-Www-D`)のほか、
+    /// `2025年5月1日(木)`・`令和7年5月1日` のような漢字・和暦の日付も受け付ける
+    /// 全角数字も受け付ける
+    pub fn parse(text: &str) -> Result<Date, JsError> {
+        parse(text).map(Date::from)
+    }
+
+    /// 年月日から生成する
+    #[wasm_bindgen(js_name = fromYmd)]
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Result<Date, JsError> {
+        koyomi::Date::from_ymd(year, month, day)
+            .map(Date::from)
+            .map_err(js_error)
+    }
+
+    /// 「年」
+    #[wasm_bindgen(getter)]
+    pub fn year(&self) -> i32 {
+        self.inner.year()
+    }
+
+    /// 「月」(1〜12)
+    #[wasm_bindgen(getter)]
+    pub fn month(&self) -> u32 {
+        self.inner.month()
+    }
+
+    /// 「日」
+    #[wasm_bindgen(getter)]
+    pub fn day(&self) -> u32 {
+        self.inner.day()
+    }
+
+    /// 曜日の番号(日曜を0とする)
+    #[wasm_bindgen(getter)]
+    pub fn weekday(&self) -> u32 {
+        self.inner.weekday().num_days_from_sunday()
+    }
+
+    /// 曜日の日本語表現(「月」など)
+    #[wasm_bindgen(getter, js_name = weekdayJapanese)]
+    pub fn weekday_japanese(&self) -> String {
+        self.inner.weekday().japanese().to_string()
+    }
+
+    /// 和暦を返す
+    /// 明治以前は `undefined` となる
+    pub fn era(&self) -> Option<Era> {
+        self.inner.era().map(Era::from)
+    }
+
+    /// 祝祭日であればその名前を返す
+    pub fn holiday(&self) -> Option<String> {
+        self.inner.holiday()
+    }
+
+    /// 営業日(土曜・日曜・祝祭日以外)かどうかを判定する
+    #[wasm_bindgen(js_name = isBusinessDay)]
+    pub fn is_business_day(&self) -> bool {
+        self.inner.is_business_day()
+    }
+
+    /// `YYYY-MM-DD` 形式の文字列を返す
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_iso_string(&self) -> String {
+        self.inner.to_string()
+    }
+}
+
+impl From<koyomi::Date> for Date {
+    fn from(inner: koyomi::Date) -> Self {
+        Date { inner }
+    }
+}
+
+/// 和暦
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct Era {
+    name: String,
+    year: i32,
+    format: String,
+}
+
+#[wasm_bindgen]
+impl Era {
+    /// 元号名
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// 和暦の年(元年は1)
+    #[wasm_bindgen(getter)]
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// 「令和元年」のような表記を返す
+    pub fn format(&self) -> String {
+        self.format.clone()
+    }
+}
+
+impl From<koyomi::Era> for Era {
+    fn from(era: koyomi::Era) -> Self {
+        Era {
+            name: era.name(),
+            year: era.year(),
+            format: era.format(),
+        }
+    }
+}
+
+/// 祝祭日
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct Holiday {
+    date: Date,
+    name: String,
+}
+
+#[wasm_bindgen]
+impl Holiday {
+    /// 祝祭日の日付
+    #[wasm_bindgen(getter)]
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// 祝祭日の名前
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+}
+
+/// 月表示
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct MonthGrid {
+    inner: koyomi::MonthGrid,
+}
+
+#[wasm_bindgen]
+impl MonthGrid {
+    /// 「年」
+    #[wasm_bindgen(getter)]
+    pub fn year(&self) -> i32 {
+        self.inner.year()
+    }
+
+    /// 「月」(1〜12)
+    #[wasm_bindgen(getter)]
+    pub fn month(&self) -> u32 {
+        self.inner.month()
+    }
+
+    /// 週(行)の数
+    #[wasm_bindgen(getter)]
+    pub fn rows(&self) -> usize {
+        self.inner.weeks().len()
+    }
+
+    /// 週の始まりから並べた曜日の日本語表現
+    pub fn weekdays(&self) -> Vec<String> {
+        self.inner
+            .weekdays()
+            .iter()
+            .map(|w| w.japanese().to_string())
+            .collect()
+    }
+
+    /// すべてのセルを返す
+    /// 1行7日分のセルが、週の順に並ぶ
+    pub fn cells(&self) -> Vec<Cell> {
+        self.inner
+            .weeks()
+            .iter()
+            .flatten()
+            .map(Cell::from)
+            .collect()
+    }
+
+    /// 指定した週(0始まり)のセルを返す
+    /// 範囲外の場合は空となる
+    pub fn week(&self, index: usize) -> Vec<Cell> {
+        self.inner
+            .weeks()
+            .get(index)
+            .map(|w| w.iter().map(Cell::from).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// 月表示の1日分のセル
+#[wasm_bindgen]
+#[derive(Clone, Debug)]
+pub struct Cell {
+    date: Date,
+    holiday: Option<String>,
+    in_month: bool,
+}
+
+#[wasm_bindgen]
+impl Cell {
+    /// セルの日付
+    #[wasm_bindgen(getter)]
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// 祝祭日の名前
+    #[wasm_bindgen(getter)]
+    pub fn holiday(&self) -> Option<String> {
+        self.holiday.clone()
+    }
+
+    /// 表示対象の月に含まれるかどうか
+    #[wasm_bindgen(getter, js_name = inMonth)]
+    pub fn in_month(&self) -> bool {
+        self.in_month
+    }
+}
+
+impl From<&koyomi::Cell> for Cell {
+    fn from(cell: &koyomi::Cell) -> Self {
+        Cell {
+            date: Date::from(cell.date()),
+            holiday: cell.holiday(),
+            in_month: cell.in_month(),
+        }
+    }
+}
+
+/// 日付文字列の祝祭日を返す
+#[wasm_bindgen]
+pub fn holiday(date: &str) -> Result<Option<String>, JsError> {
+    parse(date).map(|d| d.holiday())
+}
+
+/// 日付文字列の和暦を返す
+#[wasm_bindgen]
+pub fn era(date: &str) -> Result<Option<Era>, JsError> {
+    parse(date).map(|d| d.era().map(Era::from))
+}
+
+/// 指定年の祝祭日を日付順に返す
+#[wasm_bindgen]
+pub fn holidays(year: i32) -> Result<Vec<Holiday>, JsError> {
+    koyomi::holidays(year)
+        .map(|hs| {
+            hs.into_iter()
+                .map(|(date, name)| Holiday {
+                    date: Date::from(date),
+                    name,
+                })
+                .collect()
+        })
+        .map_err(js_error)
+}
+
+/// 月表示を生成する
+/// 週の始まりは曜日の番号で指定し、省略時は日曜となる
+#[wasm_bindgen(js_name = monthGrid)]
+pub fn month_grid(year: i32, month: u32, first_weekday: Option<u32>) -> Result<MonthGrid, JsError> {
+    let first = weekday(first_weekday.unwrap_or(0))?;
+    koyomi::MonthGrid::new(year, month, first)
+        .map(|inner| MonthGrid { inner })
+        .map_err(js_error)
+}
+
+/// 日付文字列を解釈する
+fn parse(text: &str) -> Result<koyomi::Date, JsError> {
+    koyomi::Date::parse(text).map_err(js_error)
+}
+
+/// 日曜を0とした番号から曜日を返す
+fn weekday(n: u32) -> Result<Weekday, JsError> {
+    match n {
+        0 => Ok(Weekday::Sunday),
+        1 => Ok(Weekday::Monday),
+        2 => Ok(Weekday::Tuesday),
+        3 => Ok(Weekday::Wednesday),
+        4 => Ok(Weekday::Thursday),
+        5 => Ok(Weekday::Friday),
+        6 => Ok(Weekday::Saturday),
+        _ => Err(JsError::new(&format!(
+            "曜日の番号が正しくありません: {}",
+            n
+        ))),
+    }
+}
+
+/// JavaScript の `Error` に変換する
+/// メッセージは日本語となる
+fn js_error(err: KoyomiError) -> JsError {
+    JsError::new(&err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // `JsError` の生成には JavaScript が必要なため、ここでは成功する場合のみを確認する。
+    // エラーを含む確認は `wasm-pack test --node` で実行する。
+
+    #[test]
+    fn date() {
+        let date = Date::parse("2019-05-01").unwrap();
+        assert_eq!((date.year(), date.month(), date.day()), (2019, 5, 1));
+        assert_eq!(date.weekday(), 3);
+        assert_eq!(date.weekday_japanese(), "水");
+        assert_eq!(date.holiday().unwrap(), "新天皇即位日");
+        assert_eq!(date.era().unwrap().format(), "令和元年");
+        assert!(!date.is_business_day());
+        assert_eq!(date.to_iso_string(), "2019-05-01");
+    }
+
+    #[test]
+    fn lookup_by_string() {
+        assert_eq!(holiday("2018-01-01").unwrap().unwrap(), "元日");
+        assert!(holiday("2018-01-02").unwrap().is_none());
+        assert_eq!(era("1989/01/08").unwrap().unwrap().name(), "平成");
+        assert!(era("1800-01-01").unwrap().is_none());
+    }
+
+    #[test]
+    fn holidays_of_year() {
+        let list = holidays(2018).unwrap();
+        assert_eq!(list.len(), 20);
+        assert_eq!(list[0].date().to_iso_string(), "2018-01-01");
+        assert_eq!(list[0].name(), "元日");
+    }
+
+    #[test]
+    fn grid() {
+        let grid = month_grid(2018, 4, None).unwrap();
+        assert_eq!(grid.weekdays().concat(), "日月火水木金土");
+        assert_eq!(grid.rows(), 5);
+        assert_eq!(grid.cells().len(), 35);
+        assert_eq!(grid.week(4)[1].holiday().unwrap(), "振替休日");
+        assert!(grid.week(5).is_empty());
+
+        let grid = month_grid(2018, 4, Some(1)).unwrap();
+        assert_eq!(grid.weekdays()[0], "月");
+        assert_eq!(grid.rows(), 6);
+        assert!(!grid.cells()[0].in_month());
+    }
+}
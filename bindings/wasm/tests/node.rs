@@ -0,0 +1,41 @@
+//! `wasm-pack test --node` で実行するテスト
+#![cfg(target_arch = "wasm32")]
+
+use koyomi_wasm::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn parse_date() {
+    let date = Date::parse("2019/05/01").unwrap();
+    assert_eq!(date.to_iso_string(), "2019-05-01");
+    assert_eq!(date.era().unwrap().format(), "令和元年");
+
+    assert!(Date::parse("2019-02-29").is_err());
+    assert!(Date::from_ymd(2018, 13, 1).is_err());
+}
+
+#[wasm_bindgen_test]
+fn lookup_by_string() {
+    assert_eq!(holiday("2019-10-22").unwrap().unwrap(), "即位礼正殿の儀");
+    assert!(holiday("2019-10-32").is_err());
+    assert!(era("not a date").is_err());
+}
+
+#[wasm_bindgen_test]
+fn holidays_of_year() {
+    let list = holidays(2019).unwrap();
+    assert_eq!(list.len(), 22);
+    assert!(list
+        .windows(2)
+        .all(|w| w[0].date().to_iso_string() < w[1].date().to_iso_string()));
+}
+
+#[wasm_bindgen_test]
+fn grid() {
+    let grid = month_grid(2019, 5, Some(0)).unwrap();
+    assert_eq!(grid.rows(), 5);
+    assert_eq!(grid.cells()[3].holiday().unwrap(), "新天皇即位日");
+
+    assert!(month_grid(2019, 13, None).is_err());
+    assert!(month_grid(2019, 5, Some(7)).is_err());
+}
@@ -0,0 +1,304 @@
+//! # 内閣府の祝日一覧
+//!
+//! 内閣府が公開している「国民の祝日」の CSV(`syukujitsu.csv`)を読み込む。
+//! CSV は Shift_JIS で、1行に「日付,名称」の形式で祝日・休日が並ぶ。
+//!
+//! 読み込んだ一覧は祝祭日の判定元として使えるほか、
+//! [`holiday`](fn.holiday.html) の判定と食い違う日付を洗い出すこともできる。
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use encoding_rs::SHIFT_JIS;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::holiday::{self, HolidaySource};
+use crate::{Calendar, Date, KoyomiError, KoyomiResult};
+
+/// 内閣府の祝日一覧
+///
+/// # Examples
+///
+/// ```rust
+/// use koyomi::{CabinetHolidays, Date, HolidaySource};
+///
+/// let csv = "国民の祝日・休日月日,国民の祝日・休日名称\r\n2018/1/1,元日\r\n2018/1/8,成人の日\r\n";
+/// let cabinet = CabinetHolidays::parse_csv(csv.as_bytes()).unwrap();
+///
+/// let date = Date::from_ymd(2018, 1, 8).unwrap();
+/// assert_eq!(cabinet.holiday(&date).unwrap(), "成人の日");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CabinetHolidays {
+    days: BTreeMap<Date, String>,
+}
+
+/// 内閣府の一覧と [`holiday`](fn.holiday.html) の判定が食い違う日付
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Mismatch {
+    date: Date,
+    expected: Option<String>,
+    actual: Option<String>,
+}
+
+impl CabinetHolidays {
+    /// `syukujitsu.csv` を読み込む
+    pub fn from_csv<P: AsRef<Path>>(path: P) -> KoyomiResult<Self> {
+        let bytes = fs::read(path).map_err(KoyomiError::Io)?;
+        CabinetHolidays::parse_csv(&bytes)
+    }
+
+    /// Shift_JIS の CSV を読み込む
+    /// UTF-8 として妥当なバイト列であれば UTF-8 として読み込む
+    pub fn parse_csv(bytes: &[u8]) -> KoyomiResult<Self> {
+        let text = match std::str::from_utf8(bytes) {
+            Ok(text) => text.trim_start_matches('\u{feff}').into(),
+            Err(_) => SHIFT_JIS.decode(bytes).0,
+        };
+        let mut days = BTreeMap::new();
+
+        for line in text.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+            let (date, name) = line
+                .split_once(',')
+                .ok_or_else(|| KoyomiError::InvalidFormat(line.into()))?;
+            // 見出し行は読み飛ばす
+            if !date.starts_with(|c: char| c.is_ascii_digit()) {
+                continue;
+            }
+            days.insert(Date::parse(date.trim())?, name.trim().to_owned());
+        }
+
+        Ok(CabinetHolidays { days })
+    }
+
+    /// 一覧に含まれる祝日・休日を日付順に返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::CabinetHolidays;
+    ///
+    /// let csv = "2018/1/8,成人の日\r\n2018/1/1,元日\r\n";
+    /// let cabinet = CabinetHolidays::parse_csv(csv.as_bytes()).unwrap();
+    /// let days = cabinet.days();
+    /// assert_eq!(days[0].0.to_string(), "2018-01-01");
+    /// assert_eq!(days[1].1, "成人の日");
+    /// ```
+    pub fn days(&self) -> Vec<(Date, String)> {
+        self.days.iter().map(|(d, n)| (*d, n.clone())).collect()
+    }
+
+    /// 一覧が対象とする期間を返す
+    /// 最初の祝日の年の元日から、最後の祝日の年の大晦日までとなる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::CabinetHolidays;
+    ///
+    /// let csv = "2018/1/1,元日\r\n2019/11/23,勤労感謝の日\r\n";
+    /// let cabinet = CabinetHolidays::parse_csv(csv.as_bytes()).unwrap();
+    /// let cal = cabinet.term().unwrap();
+    /// assert_eq!(cal.from(), "2018-01-01");
+    /// assert_eq!(cal.until(), "2019-12-31");
+    /// ```
+    pub fn term(&self) -> Option<Calendar> {
+        let first = self.days.keys().next()?;
+        let last = self.days.keys().next_back()?;
+        let from = Date::from_ymd(first.year(), 1, 1).ok()?;
+        let until = Date::from_ymd(last.year(), 12, 31).ok()?;
+        Calendar::new(from, until).ok()
+    }
+
+    /// 一覧が対象とする期間のうち、
+    /// [`holiday`](fn.holiday.html) の判定と祝日・休日かどうか、
+    /// またはその名称が食い違う日付を返す
+    ///
+    /// 一覧では振替休日・国民の休日がいずれも「休日」と記載されるため、
+    /// 「休日」はそのどちらとも一致するものとして扱う。
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::CabinetHolidays;
+    ///
+    /// let csv = "2018/1/1,元日\r\n2018/1/8,成人の日\r\n2018/1/9,架空の祝日\r\n";
+    /// let cabinet = CabinetHolidays::parse_csv(csv.as_bytes()).unwrap();
+    /// let mismatches = cabinet.validate();
+    /// assert_eq!(mismatches.len(), 19);
+    /// assert_eq!(mismatches[0].date().to_string(), "2018-01-09");
+    /// assert!(mismatches[0].actual().is_none());
+    /// ```
+    pub fn validate(&self) -> Vec<Mismatch> {
+        let term = match self.term() {
+            Some(term) => term,
+            None => return Vec::new(),
+        };
+
+        term.iter()
+            .filter_map(|date| {
+                let expected = self.days.get(&date).cloned();
+                let actual = holiday::holiday(&date);
+                let agree = match (&expected, &actual) {
+                    (Some(expected), Some(actual)) => same_name(expected, actual),
+                    (None, None) => true,
+                    _ => false,
+                };
+                if agree {
+                    None
+                } else {
+                    Some(Mismatch {
+                        date,
+                        expected,
+                        actual,
+                    })
+                }
+            })
+            .collect()
+    }
+}
+
+/// 一覧の名称と [`holiday`](fn.holiday.html) の名称が同じ祝日・休日を指すかどうか
+fn same_name(expected: &str, actual: &str) -> bool {
+    expected == actual || (expected == "休日" && (actual == "振替休日" || actual == "国民の休日"))
+}
+
+impl HolidaySource for CabinetHolidays {
+    fn holiday(&self, date: &Date) -> Option<String> {
+        self.days.get(date).cloned()
+    }
+}
+
+impl Mismatch {
+    /// 食い違う日付を返す
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// 内閣府の一覧での名称を返す
+    pub fn expected(&self) -> Option<String> {
+        self.expected.clone()
+    }
+
+    /// [`holiday`](fn.holiday.html) の判定結果を返す
+    pub fn actual(&self) -> Option<String> {
+        self.actual.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 内閣府の CSV と同じ Shift_JIS・CRLF の2018年分
+    fn csv_2018() -> Vec<u8> {
+        let text = "国民の祝日・休日月日,国民の祝日・休日名称\r
+2018/1/1,元日\r
+2018/1/8,成人の日\r
+2018/2/11,建国記念の日\r
+2018/2/12,休日\r
+2018/3/21,春分の日\r
+2018/4/29,昭和の日\r
+2018/4/30,休日\r
+2018/5/3,憲法記念日\r
+2018/5/4,みどりの日\r
+2018/5/5,こどもの日\r
+2018/7/16,海の日\r
+2018/8/11,山の日\r
+2018/9/17,敬老の日\r
+2018/9/23,秋分の日\r
+2018/9/24,休日\r
+2018/10/8,体育の日\r
+2018/11/3,文化の日\r
+2018/11/23,勤労感謝の日\r
+2018/12/23,天皇誕生日\r
+2018/12/24,休日\r
+";
+        SHIFT_JIS.encode(text).0.into_owned()
+    }
+
+    #[test]
+    fn parse_shift_jis() {
+        let cabinet = CabinetHolidays::parse_csv(&csv_2018()).unwrap();
+        assert_eq!(cabinet.days().len(), 20);
+
+        let date = Date::parse("2018-02-11").unwrap();
+        assert_eq!(cabinet.holiday(&date).unwrap(), "建国記念の日");
+        let date = Date::parse("2018-02-13").unwrap();
+        assert!(cabinet.holiday(&date).is_none());
+    }
+
+    #[test]
+    fn parse_utf8_with_bom() {
+        let csv = "\u{feff}2018/1/1,元日\r\n";
+        let cabinet = CabinetHolidays::parse_csv(csv.as_bytes()).unwrap();
+        let date = Date::parse("2018-01-01").unwrap();
+        assert_eq!(cabinet.holiday(&date).unwrap(), "元日");
+    }
+
+    #[test]
+    fn invalid_line() {
+        assert!(CabinetHolidays::parse_csv(b"2018/1/1").is_err());
+        assert!(CabinetHolidays::parse_csv(b"2018/13/1,x").is_err());
+    }
+
+    #[test]
+    fn agree_with_rules() {
+        // 振替休日は一覧では「休日」となるが、食い違いとはしない
+        let cabinet = CabinetHolidays::parse_csv(&csv_2018()).unwrap();
+        let mismatches = cabinet.validate();
+        assert_eq!(mismatches.len(), 1);
+
+        // 日付は一致するが名称が異なる
+        assert_eq!(mismatches[0].date(), Date::parse("2018-02-11").unwrap());
+        assert_eq!(mismatches[0].expected().unwrap(), "建国記念の日");
+        assert_eq!(mismatches[0].actual().unwrap(), "建国記念日");
+    }
+
+    #[test]
+    fn report_mismatches() {
+        let text = SHIFT_JIS
+            .decode(&csv_2018())
+            .0
+            .replace("2018/12/24,休日", "2018/12/25,休日");
+        let cabinet = CabinetHolidays::parse_csv(&SHIFT_JIS.encode(&text).0).unwrap();
+        let mismatches = cabinet.validate();
+        assert_eq!(mismatches.len(), 3);
+
+        assert_eq!(mismatches[1].date().to_string(), "2018-12-24");
+        assert!(mismatches[1].expected().is_none());
+        assert_eq!(mismatches[1].actual().unwrap(), "振替休日");
+
+        assert_eq!(mismatches[2].date().to_string(), "2018-12-25");
+        assert_eq!(mismatches[2].expected().unwrap(), "休日");
+        assert!(mismatches[2].actual().is_none());
+    }
+
+    #[test]
+    fn report_renamed_holiday() {
+        let csv = "2022/1/1,元日\r\n2022/10/10,スポーツの日\r\n";
+        let cabinet = CabinetHolidays::parse_csv(csv.as_bytes()).unwrap();
+        let mismatch = cabinet
+            .validate()
+            .into_iter()
+            .find(|m| m.date() == Date::parse("2022-10-10").unwrap())
+            .unwrap();
+        assert_eq!(mismatch.expected().unwrap(), "スポーツの日");
+        assert_eq!(mismatch.actual().unwrap(), "体育の日");
+    }
+
+    #[test]
+    fn empty_list() {
+        let cabinet = CabinetHolidays::parse_csv(b"").unwrap();
+        assert!(cabinet.term().is_none());
+        assert!(cabinet.validate().is_empty());
+    }
+
+    #[test]
+    fn missing_file() {
+        let result = CabinetHolidays::from_csv("/nonexistent/syukujitsu.csv");
+        assert!(matches!(result, Err(KoyomiError::Io(_))));
+    }
+}
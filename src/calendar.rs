@@ -1,8 +1,22 @@
 //! # カレンダー
 //!
 //! 指定期間の日付を持つカレンダーとユーティリティ関数
+use core::convert::TryFrom;
+use core::iter::{FusedIterator, StepBy};
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::{KoyomiError, KoyomiResult};
-use crate::Date;
+use crate::week::parse_week;
+use crate::{Date, FiscalYear, IsoWeek, MonthGrid, Weekday};
+
+/// 1週間の日数
+const ONE_WEEK: usize = 7;
 
 /// 指定年月が何日まであるかを返す
 ///
@@ -25,7 +39,7 @@ pub fn num_days(year: i32, month: u32) -> u32 {
         } else {
             28
         },
-        _ => unreachable!(format!("{} is invalid month!", month)),
+        _ => unreachable!("{} is invalid month!", month),
     }
 }
 
@@ -41,24 +55,37 @@ pub fn num_days(year: i32, month: u32) -> u32 {
 /// ```
 pub fn is_leap(year: i32) -> bool {
     // @see https://www.nao.ac.jp/faq/a0306.html
-    if year % 4 != 0 {
-        false
-    } else if year % 100 == 0 && year % 400 != 0 {
-        false
-    } else {
-        true
-    }
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
 }
 
 /// カレンダー
 ///
 /// 指定期間の日付を生成することができる
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Term"))]
 pub struct Calendar {
     from: Date,
     until: Date,
 }
 
+/// デシリアライズ時に期間を検証するための中間表現
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct Term {
+    from: Date,
+    until: Date,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Term> for Calendar {
+    type Error = String;
+
+    fn try_from(term: Term) -> Result<Self, Self::Error> {
+        Calendar::new(term.from, term.until).map_err(|e| e.to_string())
+    }
+}
+
 impl Calendar {
     /// カレンダーオブジェクトを生成する
     ///
@@ -88,9 +115,13 @@ impl Calendar {
     /// カレンダーを生成するためのビルダーを返す
     pub fn build<'a>() -> CalendarBuilder<'a> {
         CalendarBuilder {
+            fiscal: None,
+            fiscal_start: None,
             from: None,
             single: None,
             until: None,
+            week: None,
+            week_start: None,
         }
     }
 
@@ -110,24 +141,115 @@ impl Calendar {
         self.from.to_string()
     }
 
+    /// カレンダーの期間の日付を先頭から順に返すイテレータを返す
+    /// 日付は必要になった時点で生成する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Calendar;
+    ///
+    /// let cal = Calendar::build().single("2018").finalize().unwrap();
+    /// let mut days = cal.iter();
+    /// assert_eq!(days.next().unwrap().to_string(), "2018-01-01");
+    /// assert_eq!(days.next_back().unwrap().to_string(), "2018-12-31");
+    /// assert_eq!(days.len(), 363);
+    /// ```
+    pub fn iter(&self) -> Days {
+        Days {
+            front: self.from,
+            back: self.until,
+            done: false,
+        }
+    }
+
+    /// カレンダーの期間の日付をすべて生成する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Calendar;
+    ///
+    /// let cal = Calendar::build().single("2018").finalize().unwrap();
+    /// assert_eq!(cal.make().len(), 365);
+    /// ```
     pub fn make(&self) -> Vec<Date> {
-        let days = self.until.num_days(&self.from) + 1;
-        let mut cal = Vec::with_capacity(days as usize);
-        let mut date = self.from.tomorrow();
-
-        cal.push(self.from.clone());
-
-        loop {
-            if let Ok(d) = date {
-                if d > self.until {
-                    break;
-                }
-                date = d.tomorrow();
-                cal.push(d);
+        self.iter().collect()
+    }
+
+    /// カレンダーの期間に含まれる月ごとの月表示を返す
+    /// 前後の月で埋める日付が扱える範囲外となる月を含む場合はエラーとなる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Calendar, Weekday};
+    ///
+    /// let cal = Calendar::build()
+    ///     .from("2018-04")
+    ///     .until("2018-06")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let months = cal.months(Weekday::Sunday).unwrap();
+    /// assert_eq!(months.len(), 3);
+    /// assert_eq!(months[0].month(), 4);
+    /// ```
+    pub fn months(&self, first: Weekday) -> KoyomiResult<Vec<MonthGrid>> {
+        let (mut y, mut m) = (self.from.year(), self.from.month());
+        let mut months = Vec::new();
+
+        while (y, m) <= (self.until.year(), self.until.month()) {
+            months.push(MonthGrid::new(y, m, first)?);
+            if m == 12 {
+                y += 1;
+                m = 1;
+            } else {
+                m += 1;
             }
         }
 
-        cal
+        Ok(months)
+    }
+
+    /// 開始日から指定月数ごとの日付を返すイテレータを返す
+    /// 開始日の「日」が存在しない月は、その月の末日となる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Calendar;
+    ///
+    /// let cal = Calendar::build()
+    ///     .from("2018-01")
+    ///     .until("2018-12")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let days = cal.step_months(3).map(|d| d.to_string()).collect::<Vec<_>>();
+    /// assert_eq!(days, vec!["2018-01-01", "2018-04-01", "2018-07-01", "2018-10-01"]);
+    /// ```
+    pub fn step_months(&self, months: u32) -> MonthSteps {
+        MonthSteps {
+            day: self.from.day(),
+            year: self.from.year(),
+            month: self.from.month(),
+            step: months.max(1),
+            until: self.until,
+        }
+    }
+
+    /// 開始日から指定週数ごとの日付を返すイテレータを返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Calendar;
+    ///
+    /// let cal = Calendar::build().single("2018-01").finalize().unwrap();
+    /// let days = cal.step_weeks(2).map(|d| d.to_string()).collect::<Vec<_>>();
+    /// assert_eq!(days, vec!["2018-01-01", "2018-01-15", "2018-01-29"]);
+    /// ```
+    pub fn step_weeks(&self, weeks: u32) -> StepBy<Days> {
+        self.iter().step_by(ONE_WEEK * weeks.max(1) as usize)
     }
 
     /// 終了日の文字列表現を返す
@@ -147,6 +269,124 @@ impl Calendar {
     }
 }
 
+impl IntoIterator for Calendar {
+    type Item = Date;
+    type IntoIter = Days;
+
+    fn into_iter(self) -> Days {
+        Days {
+            front: self.from,
+            back: self.until,
+            done: false,
+        }
+    }
+}
+
+impl IntoIterator for &Calendar {
+    type Item = Date;
+    type IntoIter = Days;
+
+    fn into_iter(self) -> Days {
+        self.iter()
+    }
+}
+
+/// カレンダーの日付を1日ずつ返すイテレータ
+///
+/// 前後どちらからでも辿ることができる。
+/// 翌日・前日が存在しない日付に達した場合もそこで終了する。
+#[derive(Clone, Debug)]
+pub struct Days {
+    front: Date,
+    back: Date,
+    done: bool,
+}
+
+impl Iterator for Days {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Date> {
+        if self.done {
+            return None;
+        }
+
+        let date = self.front;
+        if self.front == self.back {
+            self.done = true;
+        } else {
+            match self.front.tomorrow() {
+                Ok(d) => self.front = d,
+                Err(_) => self.done = true,
+            }
+        }
+
+        Some(date)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = if self.done {
+            0
+        } else {
+            self.back.num_days(&self.front) as usize + 1
+        };
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Days {
+    fn next_back(&mut self) -> Option<Date> {
+        if self.done {
+            return None;
+        }
+
+        let date = self.back;
+        if self.front == self.back {
+            self.done = true;
+        } else {
+            match self.back.yesterday() {
+                Ok(d) => self.back = d,
+                Err(_) => self.done = true,
+            }
+        }
+
+        Some(date)
+    }
+}
+
+impl ExactSizeIterator for Days {}
+
+impl FusedIterator for Days {}
+
+/// 開始日から指定月数ごとの日付を返すイテレータ
+#[derive(Clone, Debug)]
+pub struct MonthSteps {
+    day: u32,
+    year: i32,
+    month: u32,
+    step: u32,
+    until: Date,
+}
+
+impl Iterator for MonthSteps {
+    type Item = Date;
+
+    fn next(&mut self) -> Option<Date> {
+        let day = self.day.min(num_days(self.year, self.month));
+        let date = Date::from_ymd(self.year, self.month, day).ok()?;
+        if date > self.until {
+            return None;
+        }
+
+        // 年が i32 に収まらない場合は、次の日付が範囲外となって終わる
+        let months = i64::from(self.month - 1) + i64::from(self.step);
+        let year = i64::from(self.year) + months / 12;
+        self.year = i32::try_from(year).unwrap_or(i32::MAX);
+        self.month = (months % 12) as u32 + 1;
+
+        Some(date)
+    }
+}
+
 /// カレンダー用ビルダー
 ///
 /// カレンダーの範囲指定には複数のユースケースがあるので、
@@ -156,11 +396,17 @@ impl Calendar {
 /// 2. 特定年のカレンダー
 /// 3. 期間を年月で指定したカレンダー
 /// 4. 期間を年で指定したカレンダー
+/// 5. 特定年度のカレンダー
+/// 6. 特定週のカレンダー
 #[derive(Debug)]
 pub struct CalendarBuilder<'a> {
+    fiscal: Option<&'a str>,
+    fiscal_start: Option<u32>,
     from: Option<&'a str>,
     single: Option<&'a str>,
     until: Option<&'a str>,
+    week: Option<&'a str>,
+    week_start: Option<Weekday>,
 }
 
 impl<'a> CalendarBuilder<'a> {
@@ -177,11 +423,21 @@ impl<'a> CalendarBuilder<'a> {
     /// let builder = Calendar::build().from("2018").until("2019").finalize();
     /// assert!(builder.is_ok());
     ///
+    /// let builder = Calendar::build().fiscal("2018").finalize();
+    /// assert!(builder.is_ok());
+    ///
+    /// let builder = Calendar::build().week("2018-W01").finalize();
+    /// assert!(builder.is_ok());
+    ///
     /// let builder = Calendar::build().from("January").finalize();
     /// assert!(builder.is_err());
     /// ```
     pub fn finalize(&self) -> KoyomiResult<Calendar> {
-        if let Some(single) = self.single {
+        if let Some(fiscal) = self.fiscal {
+            self.fiscal_calendar(fiscal)
+        } else if let Some(week) = self.week {
+            self.week_calendar(week)
+        } else if let Some(single) = self.single {
             self.single_calendar(single)
         } else {
             let from = self.date_from()?;
@@ -190,6 +446,43 @@ impl<'a> CalendarBuilder<'a> {
         }
     }
 
+    /// 単一年度を指定する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Calendar;
+    ///
+    /// let cal = Calendar::build().fiscal("2018").finalize().unwrap();
+    /// assert_eq!(cal.from(), "2018-04-01");
+    /// assert_eq!(cal.until(), "2019-03-31");
+    /// ```
+    pub fn fiscal(mut self, fiscal: &'a str) -> Self {
+        self.fiscal = Some(fiscal);
+        self
+    }
+
+    /// 年度が始まる月を指定する
+    /// 指定しない場合は4月始まりとなる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Calendar;
+    ///
+    /// let cal = Calendar::build()
+    ///     .fiscal("2018")
+    ///     .fiscal_start(10)
+    ///     .finalize()
+    ///     .unwrap();
+    /// assert_eq!(cal.from(), "2018-10-01");
+    /// assert_eq!(cal.until(), "2019-09-30");
+    /// ```
+    pub fn fiscal_start(mut self, month: u32) -> Self {
+        self.fiscal_start = Some(month);
+        self
+    }
+
     /// 期間の始まりを指定する
     ///
     /// # Examples
@@ -235,6 +528,45 @@ impl<'a> CalendarBuilder<'a> {
         self
     }
 
+    /// 単一週を `YYYY-Www` 形式で指定する
+    /// 週の始まりを指定しない場合は ISO 8601 の週となる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Calendar;
+    ///
+    /// let cal = Calendar::build().week("2025-W07").finalize().unwrap();
+    /// assert_eq!(cal.from(), "2025-02-10");
+    /// assert_eq!(cal.until(), "2025-02-16");
+    /// ```
+    pub fn week(mut self, week: &'a str) -> Self {
+        self.week = Some(week);
+        self
+    }
+
+    /// 週の始まりの曜日を指定する
+    /// 指定した場合は1月1日を含む週を第1週とする(日本式の週番号)
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Calendar, Weekday};
+    ///
+    /// // 2025年1月1日は水曜
+    /// let cal = Calendar::build()
+    ///     .week("2025-W01")
+    ///     .week_start(Weekday::Sunday)
+    ///     .finalize()
+    ///     .unwrap();
+    /// assert_eq!(cal.from(), "2024-12-29");
+    /// assert_eq!(cal.until(), "2025-01-04");
+    /// ```
+    pub fn week_start(mut self, weekday: Weekday) -> Self {
+        self.week_start = Some(weekday);
+        self
+    }
+
     /// カレンダーの開始日を導出する
     fn date_from(&self) -> KoyomiResult<Date> {
         match self.from {
@@ -266,6 +598,18 @@ impl<'a> CalendarBuilder<'a> {
         Date::from_ymd(y, m, num_days(y, m))
     }
 
+    /// 単一年度からカレンダーオブジェクトを生成する
+    fn fiscal_calendar(&self, year: &str) -> KoyomiResult<Calendar> {
+        let y = year
+            .parse()
+            .map_err(|e| KoyomiError::InvalidNumber(year.into(), e))?;
+        let fy = match self.fiscal_start {
+            Some(start) => FiscalYear::with_start(y, start)?,
+            None => FiscalYear::new(y),
+        };
+        fy.calendar()
+    }
+
     /// 単一年または単一年月からカレンダーオブジェクトを生成する
     fn single_calendar(&self, ym: &str) -> KoyomiResult<Calendar> {
         let splits = ym.split("-").collect::<Vec<_>>();
@@ -284,6 +628,25 @@ impl<'a> CalendarBuilder<'a> {
             _ => Err(KoyomiError::InvalidFormat(ym.into())),
         }
     }
+
+    /// 単一週からカレンダーオブジェクトを生成する
+    fn week_calendar(&self, week: &str) -> KoyomiResult<Calendar> {
+        let first = match self.week_start {
+            None => return week.parse::<IsoWeek>().map(|w| w.calendar()),
+            Some(first) => first,
+        };
+
+        let (y, w) = parse_week(week)?;
+        let new_year = Date::from_ymd(y, 1, 1)?;
+        let start = i64::from(w) * 7 - 7 - i64::from(new_year.weekday().days_since(first));
+        let last = if is_leap(y) { 365 } else { 364 };
+        if w == 0 || start > last {
+            return Err(KoyomiError::InvalidWeek(y, w));
+        }
+        let from = new_year.shift(start)?;
+        let until = from.shift(6)?;
+        Ok(Calendar { from, until })
+    }
 }
 
 #[cfg(test)]
@@ -361,6 +724,134 @@ mod tests {
         assert_eq!(cal[29].to_string(), "2018-04-30");
     }
 
+    #[test]
+    fn months_of_calendar() {
+        let from = Date::parse("2018-11-15").unwrap();
+        let until = Date::parse("2019-02-10").unwrap();
+        let months = Calendar::new(from, until)
+            .unwrap()
+            .months(Weekday::Sunday)
+            .unwrap();
+        assert_eq!(months.len(), 4);
+        assert_eq!((months[0].year(), months[0].month()), (2018, 11));
+        assert_eq!((months[3].year(), months[3].month()), (2019, 2));
+
+        // 262142年12月は翌月で埋める日付が範囲外となる
+        let from = Date::parse("262142-11-01").unwrap();
+        let cal = Calendar::new(from, Date::MAX).unwrap();
+        assert!(cal.months(Weekday::Sunday).is_err());
+        assert_eq!(cal.months(Weekday::Tuesday).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn iterate_calendar() {
+        let from = Date::parse("2018-04-01").unwrap();
+        let until = Date::parse("2018-04-30").unwrap();
+        let cal = Calendar::new(from, until).unwrap();
+
+        let days = cal.iter().collect::<Vec<_>>();
+        assert_eq!(days.len(), 30);
+        assert_eq!(days[29].to_string(), "2018-04-30");
+
+        let mut n = 0;
+        for d in &cal {
+            assert_eq!(d.month(), 4);
+            n += 1;
+        }
+        assert_eq!(n, 30);
+        assert_eq!(cal.into_iter().count(), 30);
+    }
+
+    #[test]
+    fn reverse_calendar() {
+        let cal = Calendar::build().single("2018-04").finalize().unwrap();
+        let days = cal.iter().rev().collect::<Vec<_>>();
+        assert_eq!(days.len(), 30);
+        assert_eq!(days[0].to_string(), "2018-04-30");
+        assert_eq!(days[29].to_string(), "2018-04-01");
+    }
+
+    #[test]
+    fn iterate_from_both_ends() {
+        let cal = Calendar::build().single("2018-04").finalize().unwrap();
+        let mut days = cal.iter();
+        assert_eq!(days.len(), 30);
+        assert_eq!(days.next().unwrap().to_string(), "2018-04-01");
+        assert_eq!(days.next_back().unwrap().to_string(), "2018-04-30");
+        assert_eq!(days.len(), 28);
+        assert_eq!(days.count(), 28);
+    }
+
+    #[test]
+    fn iterate_until_max_date() {
+        let until = Date::MAX;
+        let from = until.yesterday().unwrap();
+        let cal = Calendar::new(from, until).unwrap();
+        assert_eq!(cal.iter().count(), 2);
+        assert_eq!(cal.make().len(), 2);
+
+        let from = Date::MIN;
+        let until = from.tomorrow().unwrap();
+        let cal = Calendar::new(from, until).unwrap();
+        assert_eq!(cal.iter().rev().count(), 2);
+    }
+
+    #[test]
+    fn step_months_past_max_date() {
+        let cal = Calendar::build().single("2018-02").finalize().unwrap();
+        assert_eq!(cal.step_months(u32::MAX).count(), 1);
+
+        let from = Date::from_ymd(262_142, 11, 30).unwrap();
+        let cal = Calendar::new(from, Date::MAX).unwrap();
+        let mut steps = cal.step_months(u32::MAX - 11);
+        assert_eq!(steps.next(), Some(from));
+        assert_eq!(steps.next(), None);
+        assert_eq!(steps.next(), None);
+    }
+
+    #[test]
+    fn long_range_is_lazy() {
+        let cal = Calendar::build()
+            .from("1900")
+            .until("2099")
+            .finalize()
+            .unwrap();
+        let first = cal.iter().find(|d| d.holiday().is_some()).unwrap();
+        assert_eq!(first.to_string(), "1948-01-01");
+    }
+
+    #[test]
+    fn step_weeks() {
+        let cal = Calendar::build().single("2018-04").finalize().unwrap();
+        let days = cal.step_weeks(1).collect::<Vec<_>>();
+        assert_eq!(days.len(), 5);
+        assert_eq!(days[4].to_string(), "2018-04-29");
+
+        let days = cal.step_weeks(1).rev().collect::<Vec<_>>();
+        assert_eq!(days[0].to_string(), "2018-04-29");
+    }
+
+    #[test]
+    fn step_months() {
+        let from = Date::parse("2018-01-31").unwrap();
+        let until = Date::parse("2019-01-31").unwrap();
+        let cal = Calendar::new(from, until).unwrap();
+        let days = cal
+            .step_months(1)
+            .map(|d| d.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(days.len(), 13);
+        assert_eq!(days[1], "2018-02-28");
+        assert_eq!(days[2], "2018-03-31");
+        assert_eq!(days[12], "2019-01-31");
+
+        let days = cal
+            .step_months(5)
+            .map(|d| d.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(days, vec!["2018-01-31", "2018-06-30", "2018-11-30"]);
+    }
+
     #[test]
     fn calendar_until() {
         let from = Date::parse("2018-04-01").unwrap();
@@ -381,6 +872,32 @@ mod tests {
         assert!(c.is_err());
     }
 
+    #[test]
+    fn builder_fiscal() {
+        let c = Calendar::build().fiscal("2018").finalize().unwrap();
+        assert_eq!(c.from(), "2018-04-01");
+        assert_eq!(c.until(), "2019-03-31");
+
+        let c = Calendar::build()
+            .fiscal("2018")
+            .fiscal_start(1)
+            .finalize()
+            .unwrap();
+        assert_eq!(c.from(), "2018-01-01");
+        assert_eq!(c.until(), "2018-12-31");
+
+        let c = Calendar::build().fiscal("abc").finalize();
+        assert!(c.is_err());
+
+        let c = Calendar::build().fiscal("2018").fiscal_start(0).finalize();
+        assert!(c.is_err());
+
+        let c = Calendar::build().fiscal("300000").finalize();
+        assert!(c.is_err());
+        let c = Calendar::build().fiscal("262142").finalize();
+        assert!(c.is_err());
+    }
+
     #[test]
     fn builder_between() {
         let c = Calendar::build().from("2017").until("2018").finalize();
@@ -404,4 +921,39 @@ mod tests {
         let c = Calendar::build().from("2017").until("abc").finalize();
         assert!(c.is_err());
     }
+
+    #[test]
+    fn builder_week() {
+        let c = Calendar::build().week("2020-W53").finalize().unwrap();
+        assert_eq!(c.from(), "2020-12-28");
+        assert_eq!(c.until(), "2021-01-03");
+
+        let c = Calendar::build().week("2021-W53").finalize();
+        assert!(c.is_err());
+
+        let c = Calendar::build().week("2021-53").finalize();
+        assert!(c.is_err());
+    }
+
+    #[test]
+    fn builder_week_start() {
+        // 2023年は日曜に始まり日曜に終わる
+        let build = |week| {
+            Calendar::build()
+                .week(week)
+                .week_start(Weekday::Sunday)
+                .finalize()
+        };
+        let c = build("2023-W01").unwrap();
+        assert_eq!(c.from(), "2023-01-01");
+        assert_eq!(c.until(), "2023-01-07");
+
+        let c = build("2023-W53").unwrap();
+        assert_eq!(c.from(), "2023-12-31");
+        assert_eq!(c.until(), "2024-01-06");
+
+        assert!(build("2023-W00").is_err());
+        let err = build("2023-W54").unwrap_err();
+        assert!(matches!(err, KoyomiError::InvalidWeek(2023, 54)));
+    }
 }
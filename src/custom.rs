@@ -0,0 +1,412 @@
+//! # 独自の休日
+//!
+//! 会社の休業日や学校の創立記念日など、国民の祝日以外の休日を定義する。
+//! iCalendar 形式のファイルから読み込むこともできる。
+use std::fs;
+use std::path::Path;
+
+use crate::calendar::num_days;
+use crate::holiday::HolidaySource;
+use crate::ical::{self, Event, Rule};
+use crate::{Date, KoyomiError, KoyomiResult};
+
+/// 独自の休日の集まり
+///
+/// [`National`](struct.National.html) と組み合わせることで、
+/// 国民の祝日とあわせて判定できる。
+///
+/// # Examples
+///
+/// ```rust
+/// use koyomi::{CustomHolidays, Date, HolidaySource, National};
+///
+/// let ics = "BEGIN:VCALENDAR\r
+/// BEGIN:VEVENT\r
+/// DTSTART;VALUE=DATE:20181229\r
+/// DTEND;VALUE=DATE:20190104\r
+/// RRULE:FREQ=YEARLY\r
+/// SUMMARY:年末年始休業\r
+/// END:VEVENT\r
+/// END:VCALENDAR\r
+/// ";
+/// let custom = CustomHolidays::parse_ics(ics).unwrap();
+/// let holidays = National.or(custom);
+///
+/// let date = Date::from_ymd(2020, 1, 1).unwrap();
+/// assert_eq!(holidays.holiday(&date).unwrap(), "元日");
+/// let date = Date::from_ymd(2020, 1, 2).unwrap();
+/// assert_eq!(holidays.holiday(&date).unwrap(), "年末年始休業");
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CustomHolidays {
+    events: Vec<Event>,
+}
+
+impl CustomHolidays {
+    /// 空の集まりを生成する
+    pub fn new() -> Self {
+        CustomHolidays { events: Vec::new() }
+    }
+
+    /// iCalendar 形式のファイルから休日を読み込む
+    /// 終日の VEVENT のみを対象とし、繰り返しは `FREQ=YEARLY` のみに対応する
+    /// BYMONTH・BYMONTHDAY・BYDAY による日付の指定と、EXDATE による除外も扱う
+    pub fn from_ics<P: AsRef<Path>>(path: P) -> KoyomiResult<Self> {
+        let text = fs::read_to_string(path).map_err(KoyomiError::Io)?;
+        CustomHolidays::parse_ics(&text)
+    }
+
+    /// iCalendar 形式の文字列から休日を読み込む
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{CustomHolidays, Date, HolidaySource};
+    ///
+    /// let ics = "BEGIN:VEVENT\r
+    /// DTSTART;VALUE=DATE:20180601\r
+    /// SUMMARY:創立記念日\r
+    /// END:VEVENT\r
+    /// ";
+    /// let custom = CustomHolidays::parse_ics(ics).unwrap();
+    /// let date = Date::from_ymd(2018, 6, 1).unwrap();
+    /// assert_eq!(custom.holiday(&date).unwrap(), "創立記念日");
+    /// ```
+    pub fn parse_ics(text: &str) -> KoyomiResult<Self> {
+        Ok(CustomHolidays {
+            events: ical::parse_events(text)?,
+        })
+    }
+
+    /// 1日だけの休日を追加する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{CustomHolidays, Date, HolidaySource};
+    ///
+    /// let mut custom = CustomHolidays::new();
+    /// custom.add(Date::from_ymd(2018, 6, 1).unwrap(), "創立記念日");
+    ///
+    /// let date = Date::from_ymd(2019, 6, 1).unwrap();
+    /// assert!(custom.holiday(&date).is_none());
+    /// ```
+    pub fn add(&mut self, date: Date, name: &str) {
+        self.push(date, name, None);
+    }
+
+    /// 毎年同じ日付の休日を追加する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{CustomHolidays, Date, HolidaySource};
+    ///
+    /// let mut custom = CustomHolidays::new();
+    /// custom.add_yearly(Date::from_ymd(2018, 6, 1).unwrap(), "創立記念日");
+    ///
+    /// let date = Date::from_ymd(2019, 6, 1).unwrap();
+    /// assert_eq!(custom.holiday(&date).unwrap(), "創立記念日");
+    /// let date = Date::from_ymd(2017, 6, 1).unwrap();
+    /// assert!(custom.holiday(&date).is_none());
+    /// ```
+    pub fn add_yearly(&mut self, date: Date, name: &str) {
+        self.push(date, name, Some(Rule::yearly()));
+    }
+
+    /// 休日を追加する
+    fn push(&mut self, start: Date, name: &str, rule: Option<Rule>) {
+        self.events.push(Event {
+            summary: name.into(),
+            start,
+            days: 1,
+            rule,
+            exdates: Vec::new(),
+        });
+    }
+}
+
+impl HolidaySource for CustomHolidays {
+    fn holiday(&self, date: &Date) -> Option<String> {
+        self.events
+            .iter()
+            .find(|e| occurs(e, date))
+            .map(|e| e.summary.clone())
+    }
+}
+
+/// 指定日が予定の期間に含まれるかを判定する
+fn occurs(event: &Event, date: &Date) -> bool {
+    // 複数日の予定もあるため、期間の日数分さかのぼって各回の初日を確認する
+    (0..event.days).any(|back| match date.shift(-back) {
+        Ok(start) => is_start(event, &start) && !event.exdates.contains(&start),
+        Err(_) => false,
+    })
+}
+
+/// 指定日が予定のいずれかの回の初日かどうかを判定する
+fn is_start(event: &Event, date: &Date) -> bool {
+    let rule = match event.rule {
+        Some(ref rule) => rule,
+        None => return date == &event.start,
+    };
+    if date < &event.start || matches!(rule.until, Some(ref u) if date > u) {
+        return false;
+    }
+    if date != &event.start && !recurs(event, rule, date) {
+        return false;
+    }
+
+    match rule.count {
+        Some(count) => within_count(event, rule, date, count),
+        None => true,
+    }
+}
+
+/// 指定日が繰り返しの規則に当てはまるかを判定する
+/// DTSTART は規則に当てはまらなくても最初の回となる
+fn recurs(event: &Event, rule: &Rule, date: &Date) -> bool {
+    let elapsed = date.year() - event.start.year();
+    if elapsed % rule.interval as i32 != 0 {
+        return false;
+    }
+    if !rule.months.is_empty() && !rule.months.contains(&date.month()) {
+        return false;
+    }
+
+    let day = date.day() as i32;
+    let last = num_days(date.year(), date.month()) as i32;
+    if !rule.month_days.is_empty() {
+        if !rule
+            .month_days
+            .iter()
+            .any(|&d| d == day || d == day - last - 1)
+        {
+            return false;
+        }
+    } else if rule.weekdays.is_empty() {
+        // 日の指定がなければ DTSTART と同じ日(BYMONTH もなければ同じ月日)とする
+        let month = rule.months.is_empty() && date.month() != event.start.month();
+        if month || date.day() != event.start.day() {
+            return false;
+        }
+    }
+    if rule.weekdays.is_empty() {
+        return true;
+    }
+
+    // 第n は BYMONTH があれば月の中で、なければ年の中で数える
+    let (index, len) = if rule.months.is_empty() {
+        let ordinal = (1..date.month())
+            .map(|m| num_days(date.year(), m))
+            .sum::<u32>();
+        let days = (1..=12).map(|m| num_days(date.year(), m)).sum::<u32>();
+        (ordinal as i32 + day - 1, days as i32)
+    } else {
+        (day - 1, last)
+    };
+    let nth = index / 7 + 1;
+    let nth_back = -((len - 1 - index) / 7 + 1);
+    rule.weekdays.iter().any(|&(n, weekday)| {
+        weekday == date.weekday() && !matches!(n, Some(n) if n != nth && n != nth_back)
+    })
+}
+
+/// 指定日までの回数が COUNT 以内かを判定する
+/// 除外された回(EXDATE)も回数に含める
+fn within_count(event: &Event, rule: &Rule, date: &Date, count: u32) -> bool {
+    let mut nth = 0;
+    let mut day = event.start;
+    while &day <= date {
+        if day == event.start || recurs(event, rule, &day) {
+            nth += 1;
+            if nth > count {
+                return false;
+            }
+        }
+        day = match day.tomorrow() {
+            Ok(day) => day,
+            Err(_) => break,
+        };
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(events: &str) -> CustomHolidays {
+        let ics = format!(
+            "BEGIN:VCALENDAR\r\nVERSION:2.0\r\n{}END:VCALENDAR\r\n",
+            events
+        );
+        CustomHolidays::parse_ics(&ics).unwrap()
+    }
+
+    fn holiday(custom: &CustomHolidays, date: &str) -> Option<String> {
+        custom.holiday(&Date::parse(date).unwrap())
+    }
+
+    #[test]
+    fn single_day() {
+        let custom = parse(
+            "BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20180601\r\nSUMMARY:創立記念日\r\nEND:VEVENT\r\n",
+        );
+        assert_eq!(holiday(&custom, "2018-06-01").unwrap(), "創立記念日");
+        assert!(holiday(&custom, "2018-06-02").is_none());
+        assert!(holiday(&custom, "2019-06-01").is_none());
+    }
+
+    #[test]
+    fn multiple_days() {
+        let custom = parse(
+            "BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20181229\r\nDTEND;VALUE=DATE:20190104\r\nSUMMARY:年末年始休業\r\nEND:VEVENT\r\n",
+        );
+        assert!(holiday(&custom, "2018-12-28").is_none());
+        assert!(holiday(&custom, "2018-12-29").is_some());
+        assert!(holiday(&custom, "2019-01-03").is_some());
+        assert!(holiday(&custom, "2019-01-04").is_none());
+    }
+
+    #[test]
+    fn yearly_with_count() {
+        let custom = parse(
+            "BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20181229\r\nDTEND;VALUE=DATE:20190104\r\nRRULE:FREQ=YEARLY;COUNT=2\r\nSUMMARY:年末年始休業\r\nEND:VEVENT\r\n",
+        );
+        assert!(holiday(&custom, "2019-12-31").is_some());
+        assert!(holiday(&custom, "2020-01-03").is_some());
+        assert!(holiday(&custom, "2020-12-29").is_none());
+        assert!(holiday(&custom, "2017-12-29").is_none());
+    }
+
+    #[test]
+    fn yearly_with_interval_and_until() {
+        let custom = parse(
+            "BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20180601\r\nRRULE:FREQ=YEARLY;INTERVAL=2;UNTIL=20221231T000000Z\r\nSUMMARY:周年行事\r\nEND:VEVENT\r\n",
+        );
+        assert!(holiday(&custom, "2018-06-01").is_some());
+        assert!(holiday(&custom, "2019-06-01").is_none());
+        assert!(holiday(&custom, "2020-06-01").is_some());
+        assert!(holiday(&custom, "2022-06-01").is_some());
+        assert!(holiday(&custom, "2024-06-01").is_none());
+    }
+
+    #[test]
+    fn leap_day() {
+        let custom = parse(
+            "BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20160229\r\nRRULE:FREQ=YEARLY\r\nSUMMARY:閏日\r\nEND:VEVENT\r\n",
+        );
+        assert!(holiday(&custom, "2020-02-29").is_some());
+        assert!(holiday(&custom, "2019-02-28").is_none());
+        assert!(holiday(&custom, "2019-03-01").is_none());
+    }
+
+    #[test]
+    fn yearly_by_month_and_day() {
+        // 成人の日(1月の第2月曜日)、2020年は除外する
+        let custom = parse(
+            "BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20180108\r\nRRULE:FREQ=YEARLY;BYMONTH=1;BYDAY=2MO\r\nEXDATE;VALUE=DATE:20200113\r\nSUMMARY:成人式\r\nEND:VEVENT\r\n",
+        );
+        assert!(holiday(&custom, "2018-01-08").is_some());
+        assert!(holiday(&custom, "2019-01-08").is_none());
+        assert!(holiday(&custom, "2019-01-14").is_some());
+        assert!(holiday(&custom, "2019-01-07").is_none());
+        assert!(holiday(&custom, "2020-01-13").is_none());
+        assert!(holiday(&custom, "2021-01-11").is_some());
+    }
+
+    #[test]
+    fn yearly_by_last_weekday_and_month_day() {
+        let custom = parse(
+            "BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20180528\r\nRRULE:FREQ=YEARLY;BYMONTH=5;BYDAY=-1MO;COUNT=2\r\nSUMMARY:最終月曜\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20180228\r\nRRULE:FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=-1\r\nSUMMARY:月末\r\nEND:VEVENT\r\n",
+        );
+        assert!(holiday(&custom, "2019-05-27").is_some());
+        assert!(holiday(&custom, "2019-05-20").is_none());
+        assert!(holiday(&custom, "2020-05-25").is_none());
+
+        assert!(holiday(&custom, "2019-02-28").is_some());
+        assert!(holiday(&custom, "2020-02-28").is_none());
+        assert!(holiday(&custom, "2020-02-29").is_some());
+    }
+
+    #[test]
+    fn yearly_by_day_in_year() {
+        // BYMONTH がなければ第n は年の中で数える
+        let custom = parse(
+            "BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20180101\r\nRRULE:FREQ=YEARLY;BYDAY=1MO,-1FR\r\nEXDATE:20190104,20191227\r\nSUMMARY:棚卸し\r\nEND:VEVENT\r\n",
+        );
+        assert!(holiday(&custom, "2019-01-07").is_some());
+        assert!(holiday(&custom, "2019-02-04").is_none());
+        assert!(holiday(&custom, "2018-12-28").is_some());
+        assert!(holiday(&custom, "2019-12-27").is_none());
+        assert!(holiday(&custom, "2020-12-25").is_some());
+    }
+
+    #[test]
+    fn reject_unsupported_by_rules() {
+        for rule in &[
+            "BYWEEKNO=20",
+            "BYYEARDAY=100",
+            "BYSETPOS=-1",
+            "BYDAY=XX",
+            "BYMONTH=13",
+        ] {
+            let ics = format!(
+                "BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20180601\r\nRRULE:FREQ=YEARLY;{}\r\nEND:VEVENT\r\n",
+                rule
+            );
+            assert!(CustomHolidays::parse_ics(&ics).is_err(), "{}", rule);
+        }
+    }
+
+    #[test]
+    fn skip_timed_and_other_rules() {
+        let custom = parse(
+            "BEGIN:VEVENT\r\nDTSTART:20180601T090000\r\nSUMMARY:会議\r\nEND:VEVENT\r\nBEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20180601\r\nRRULE:FREQ=WEEKLY\r\nSUMMARY:定休日\r\nEND:VEVENT\r\n",
+        );
+        assert!(holiday(&custom, "2018-06-01").is_none());
+    }
+
+    #[test]
+    fn folded_and_escaped_summary() {
+        let custom = parse(
+            "BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20180601\r\nSUMMARY:創立\r\n 記念日\\, 休業\r\nEND:VEVENT\r\n",
+        );
+        assert_eq!(holiday(&custom, "2018-06-01").unwrap(), "創立記念日, 休業");
+    }
+
+    #[test]
+    fn invalid_date() {
+        let ics = "BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20181301\r\nEND:VEVENT\r\n";
+        assert!(CustomHolidays::parse_ics(ics).is_err());
+    }
+
+    #[test]
+    fn missing_file() {
+        let result = CustomHolidays::from_ics("/nonexistent/holidays.ics");
+        assert!(matches!(result, Err(KoyomiError::Io(_))));
+    }
+
+    #[test]
+    fn together_with_national() {
+        use crate::holiday::National;
+
+        let custom = parse(
+            "BEGIN:VEVENT\r\nDTSTART;VALUE=DATE:20180101\r\nDTEND;VALUE=DATE:20180104\r\nSUMMARY:年始休業\r\nEND:VEVENT\r\n",
+        );
+        let holidays = National.or(custom);
+        assert_eq!(
+            holidays
+                .holiday(&Date::parse("2018-01-01").unwrap())
+                .unwrap(),
+            "元日"
+        );
+        assert_eq!(
+            holidays
+                .holiday(&Date::parse("2018-01-02").unwrap())
+                .unwrap(),
+            "年始休業"
+        );
+    }
+}
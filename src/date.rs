@@ -8,23 +8,79 @@
 //! - 曜日
 //! - 和暦
 //! - 祝祭日
-use std::cmp::Ordering;
-use std::fmt;
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
 
-use chrono::{Datelike, NaiveDate, Weekday as ChronoWeekday};
+use alloc::string::String;
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeZone, Utc, Weekday as ChronoWeekday};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use self::Weekday::*;
 use super::{KoyomiError, KoyomiResult};
+use crate::calendar::num_days;
 use crate::era;
+use crate::format;
+use crate::fiscal::{FiscalYear, Half, FISCAL_START};
 use crate::holiday;
+use crate::week;
+use crate::{IsoWeek, Locale};
+
+/// 日本標準時の UTC からの時差(秒)
+#[cfg(feature = "chrono")]
+const JST_OFFSET: i32 = 9 * 3600;
+
+/// 扱える最小の年(`chrono::NaiveDate` と同じ)
+const MIN_YEAR: i32 = -262_143;
+
+/// 扱える最大の年(`chrono::NaiveDate` と同じ)
+const MAX_YEAR: i32 = 262_142;
+
+/// 400年(グレゴリオ暦の1周期)の日数
+const DAYS_PER_ERA: i64 = 146_097;
+
+/// 0000-03-01 から 1970-01-01 までの日数
+const UNIX_EPOCH_DAYS: i64 = 719_468;
+
+/// 0000-12-31(`chrono` の紀元 0 日目)から 1970-01-01 までの日数
+#[cfg(feature = "chrono")]
+const CE_EPOCH_DAYS: i32 = 719_163;
+
+/// 月曜から順に並べた曜日
+const WEEKDAYS: [Weekday; 7] = [
+    Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday,
+];
 
 /// 曜日
 ///
-/// 月曜を週の始まりとする。
-/// [`chrono::Weekday`]から生成することもできる。
+/// 月曜を週の始まりとし、比較も月曜を最小とする。
+/// 日本の壁掛けカレンダーのように日曜始まりで並べる場合は、
+/// [`num_days_from_sunday`](#method.num_days_from_sunday) で並べ替える。
+///
+/// [`chrono::Weekday`]と相互に変換できる。
+/// 文字列(「月」・「月曜」・「月曜日」・"Mon"・"Monday"・「げつ」など)から生成することもできる。
 ///
 /// [chrono::Weekday]: https://docs.rs/chrono/0.4.0/chrono/enum.Weekday.html
-#[derive(Clone, Debug, Eq, PartialEq)]
+///
+/// # Examples
+///
+/// ```rust
+/// use koyomi::Weekday;
+///
+/// let mut weekdays = vec![Weekday::Sunday, Weekday::Wednesday, Weekday::Monday];
+/// weekdays.sort();
+/// assert_eq!(weekdays, [Weekday::Monday, Weekday::Wednesday, Weekday::Sunday]);
+///
+/// weekdays.sort_by_key(|w| w.num_days_from_sunday());
+/// assert_eq!(weekdays, [Weekday::Sunday, Weekday::Monday, Weekday::Wednesday]);
+///
+/// assert_eq!("月曜".parse::<Weekday>().unwrap(), Weekday::Monday);
+/// assert_eq!(Weekday::Monday.to_string(), "月");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Weekday {
     Monday,
     Tuesday,
@@ -57,8 +113,165 @@ impl Weekday {
             Sunday => '日',
         }
     }
+
+    /// 曜日の短い名前を指定した言語で返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Locale, Weekday};
+    ///
+    /// let w = Weekday::Monday;
+    /// assert_eq!(w.name_in(Locale::Japanese), "月");
+    /// assert_eq!(w.name_in(Locale::English), "Mon");
+    /// assert_eq!(w.name_in(Locale::Kana), "げつ");
+    /// ```
+    pub fn name_in(&self, locale: Locale) -> &'static str {
+        locale.weekday(*self)
+    }
+
+    /// 曜日の名前を指定した言語で返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Locale, Weekday};
+    ///
+    /// let w = Weekday::Monday;
+    /// assert_eq!(w.long_name_in(Locale::Japanese), "月曜日");
+    /// assert_eq!(w.long_name_in(Locale::English), "Monday");
+    /// assert_eq!(w.long_name_in(Locale::Kana), "げつようび");
+    /// ```
+    pub fn long_name_in(&self, locale: Locale) -> &'static str {
+        locale.long_weekday(*self)
+    }
+
+    /// 曜日の日本語の名前(「月曜日」など)を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Weekday;
+    ///
+    /// assert_eq!(Weekday::Monday.long_japanese(), "月曜日");
+    /// ```
+    pub fn long_japanese(&self) -> &'static str {
+        self.long_name_in(Locale::Japanese)
+    }
+
+    /// 翌日の曜日を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Weekday;
+    ///
+    /// assert_eq!(Weekday::Monday.succ(), Weekday::Tuesday);
+    /// assert_eq!(Weekday::Sunday.succ(), Weekday::Monday);
+    /// ```
+    pub fn succ(&self) -> Weekday {
+        nth_weekday(i64::from(self.num_days_from_monday()) + 1)
+    }
+
+    /// 前日の曜日を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Weekday;
+    ///
+    /// assert_eq!(Weekday::Tuesday.pred(), Weekday::Monday);
+    /// assert_eq!(Weekday::Monday.pred(), Weekday::Sunday);
+    /// ```
+    pub fn pred(&self) -> Weekday {
+        nth_weekday(i64::from(self.num_days_from_monday()) - 1)
+    }
+
+    /// 月曜を1とした番号(1〜7)を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Weekday;
+    ///
+    /// assert_eq!(Weekday::Monday.number_from_monday(), 1);
+    /// assert_eq!(Weekday::Sunday.number_from_monday(), 7);
+    /// ```
+    pub fn number_from_monday(&self) -> u32 {
+        self.num_days_from_monday() + 1
+    }
+
+    /// 日曜を1とした番号(1〜7)を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Weekday;
+    ///
+    /// assert_eq!(Weekday::Sunday.number_from_sunday(), 1);
+    /// assert_eq!(Weekday::Saturday.number_from_sunday(), 7);
+    /// ```
+    pub fn number_from_sunday(&self) -> u32 {
+        self.num_days_from_sunday() + 1
+    }
+
+    /// 月曜を0とした番号(0〜6)を返す
+    pub fn num_days_from_monday(&self) -> u32 {
+        *self as u32
+    }
+
+    /// 日曜を0とした番号(0〜6)を返す
+    /// `Date.prototype.getDay()` などと同じ番号となる
+    pub fn num_days_from_sunday(&self) -> u32 {
+        self.days_since(Sunday)
+    }
+
+    /// 指定した曜日から何日後の曜日かを返す
+    /// 週の始まりを指定して、週の何日目(0始まり)かを求めるのに使う
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Weekday;
+    ///
+    /// assert_eq!(Weekday::Wednesday.days_since(Weekday::Monday), 2);
+    /// assert_eq!(Weekday::Monday.days_since(Weekday::Sunday), 1);
+    /// assert_eq!(Weekday::Saturday.days_since(Weekday::Sunday), 6);
+    /// ```
+    pub fn days_since(&self, other: Weekday) -> u32 {
+        (self.num_days_from_monday() + 7 - other.num_days_from_monday()) % 7
+    }
+}
+
+impl fmt::Display for Weekday {
+    /// 日本語表現(「月」など)で表示する
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.japanese())
+    }
+}
+
+impl FromStr for Weekday {
+    type Err = KoyomiError;
+
+    /// 日本語・英語・読みの曜日の名前から生成する
+    /// 日本語は「月」・「月曜」・「月曜日」、英語は大文字と小文字を区別しない
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let locales = [Locale::Japanese, Locale::English, Locale::Kana];
+        WEEKDAYS
+            .iter()
+            .find(|w| {
+                s.strip_suffix('曜') == Some(w.name_in(Locale::Japanese))
+                    || locales.iter().any(|&l| {
+                        s.eq_ignore_ascii_case(w.name_in(l))
+                            || s.eq_ignore_ascii_case(w.long_name_in(l))
+                    })
+            })
+            .copied()
+            .ok_or_else(|| KoyomiError::InvalidFormat(s.into()))
+    }
 }
 
+#[cfg(feature = "chrono")]
 impl From<ChronoWeekday> for Weekday {
     /// [`chrono::Weekday`]から対応する曜日を生成する
     ///
@@ -76,21 +289,52 @@ impl From<ChronoWeekday> for Weekday {
     }
 }
 
+#[cfg(feature = "chrono")]
+impl From<Weekday> for ChronoWeekday {
+    /// 曜日を対応する[`chrono::Weekday`]に変換する
+    ///
+    /// [`chrono::Weekday`]: https://docs.rs/chrono/0.4.0/chrono/enum.Weekday.html
+    fn from(weekday: Weekday) -> Self {
+        match weekday {
+            Monday => ChronoWeekday::Mon,
+            Tuesday => ChronoWeekday::Tue,
+            Wednesday => ChronoWeekday::Wed,
+            Thursday => ChronoWeekday::Thu,
+            Friday => ChronoWeekday::Fri,
+            Saturday => ChronoWeekday::Sat,
+            Sunday => ChronoWeekday::Sun,
+        }
+    }
+}
+
 /// 日付
 ///
 /// カレンダーのベースとなる構造体。
 /// 文字列または(年, 月, 日)から生成する。
-#[derive(Clone, Debug, Eq, PartialEq)]
+///
+/// 内部では 1970-01-01 からの日数のみを持つため `Copy` であり、
+/// 比較や日数の計算は整数演算となる。
+/// 年月日と曜日は必要になった時点で日数から求める。
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Date {
-    year: i32,
-    month: u32,
-    day: u32,
-    weekday: Weekday,
+    days: i32,
 }
 
 impl Date {
+    /// 扱える最小の日付
+    pub const MIN: Date = Date {
+        days: days_from_civil(MIN_YEAR, 1, 1) as i32,
+    };
+
+    /// 扱える最大の日付
+    pub const MAX: Date = Date {
+        days: days_from_civil(MAX_YEAR, 12, 31) as i32,
+    };
+
     /// 文字列からオブジェクトを生成する
-    /// 文字列は `Y-m-d` か `Y/m/d` 形式のみ受け付ける
+    /// 文字列は `Y-m-d`・`Y/m/d`・`Ymd`(8桁)形式か ISO 8601 の週日付(`Y-Www-D`)、
+    /// または `2025年5月1日(木)`・`令和7年5月1日` のような日本語の日付のみ受け付ける
+    /// 全角数字も受け付ける
     ///
     /// # Examples
     ///
@@ -105,12 +349,72 @@ impl Date {
     ///
     /// let date = Date::parse("2018-01-32");
     /// assert!(date.is_err());
+    ///
+    /// // 2025年第7週の水曜日
+    /// let date = Date::parse("2025-W07-3").unwrap();
+    /// assert_eq!(date.to_string(), "2025-02-12");
+    ///
+    /// let date = Date::parse("20250501").unwrap();
+    /// assert_eq!(Date::parse("令和7年5月1日").unwrap(), date);
+    /// assert_eq!(Date::parse("２０２５年５月１日(木)").unwrap(), date);
     /// ```
     pub fn parse(fmt: &str) -> KoyomiResult<Self> {
-        NaiveDate::parse_from_str(fmt, "%Y-%m-%d")
-            .or(NaiveDate::parse_from_str(fmt, "%Y/%m/%d"))
-            .map_err(|_| KoyomiError::InvalidFormat(fmt.into()))
-            .map(|d| Date::from(d))
+        if fmt.contains("-W") {
+            return week::parse_week_date(fmt);
+        }
+        if !fmt.is_ascii() {
+            return format::parse_japanese(fmt);
+        }
+        if fmt.len() == 8 && fmt.bytes().all(|b| b.is_ascii_digit()) {
+            return format::parse(fmt, format::COMPACT);
+        }
+
+        let invalid = || KoyomiError::InvalidFormat(fmt.into());
+        let (sign, body) = match fmt.strip_prefix('-') {
+            Some(body) => (-1, body),
+            None => (1, fmt.strip_prefix('+').unwrap_or(fmt)),
+        };
+        let sep = if body.contains('/') { '/' } else { '-' };
+
+        let mut parts = body.split(sep);
+        let mut number = || {
+            parts
+                .next()
+                .filter(|p| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit()))
+                .and_then(|p| p.parse::<i64>().ok())
+                .ok_or_else(invalid)
+        };
+        let (year, month, day) = (number()?, number()?, number()?);
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+
+        let year = i32::try_from(sign * year).map_err(|_| invalid())?;
+        let month = u32::try_from(month).map_err(|_| invalid())?;
+        let day = u32::try_from(day).map_err(|_| invalid())?;
+        Date::from_ymd(year, month, day)
+    }
+
+    /// 書式を指定して文字列からオブジェクトを生成する
+    /// 書式の指定子は [`format`](format/index.html) を参照
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{format, Date};
+    ///
+    /// let date = Date::parse_from_str("2025年5月1日(木)", format::KANJI).unwrap();
+    /// assert_eq!(date.to_string(), "2025-05-01");
+    ///
+    /// let date = Date::parse_from_str("01.05.2025", "%d.%m.%Y").unwrap();
+    /// assert_eq!(date.to_string(), "2025-05-01");
+    ///
+    /// // 曜日が合わない
+    /// let date = Date::parse_from_str("2025年5月1日(金)", format::KANJI);
+    /// assert!(date.is_err());
+    /// ```
+    pub fn parse_from_str(s: &str, pattern: &str) -> KoyomiResult<Self> {
+        format::parse(s, pattern)
     }
 
     /// 年月日からオブジェクトを生成する
@@ -127,8 +431,154 @@ impl Date {
     /// assert!(date.is_err());
     /// ```
     pub fn from_ymd(year: i32, month: u32, day: u32) -> KoyomiResult<Self> {
-        let ymd = format!("{:<4}-{:<02}-{:<02}", year, month, day);
-        Date::parse(&ymd)
+        if !(1..=12).contains(&month) {
+            return Err(KoyomiError::InvalidMonth(month));
+        }
+        if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+            return Err(KoyomiError::OutOfRange(year, month, day));
+        }
+        if day < 1 || day > num_days(year, month) {
+            return Err(KoyomiError::InvalidDay(year, month, day));
+        }
+
+        Ok(Date::from_days(days_from_civil(year, month, day)))
+    }
+
+    /// 日本時間での今日の日付を返す
+    ///
+    /// システムのタイムゾーンに関わらず、UTC の現在時刻を日本標準時(UTC+9)に変換する。
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Date;
+    ///
+    /// let today = Date::today_jst();
+    /// assert!(today.year() >= 2018);
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn today_jst() -> Self {
+        Date::from_datetime(&Utc::now())
+    }
+
+    /// タイムゾーン付きの日時から、日本時間での日付を生成する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::{TimeZone, Utc};
+    /// use koyomi::Date;
+    ///
+    /// // UTC では12月31日だが、日本時間では元日
+    /// let utc = Utc.with_ymd_and_hms(2017, 12, 31, 15, 0, 0).unwrap();
+    /// let date = Date::from_datetime(&utc);
+    /// assert_eq!(date.to_string(), "2018-01-01");
+    /// assert_eq!(date.holiday().unwrap(), "元日");
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn from_datetime<Tz: TimeZone>(datetime: &DateTime<Tz>) -> Self {
+        Date::from(datetime.with_timezone(&jst()).date_naive())
+    }
+
+    /// UNIX 時間(秒)から、日本時間での日付を生成する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Date;
+    ///
+    /// // 2018-12-31T15:00:00Z
+    /// let date = Date::from_timestamp(1_546_268_400).unwrap();
+    /// assert_eq!(date.to_string(), "2019-01-01");
+    ///
+    /// assert!(Date::from_timestamp(i64::MAX).is_err());
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn from_timestamp(secs: i64) -> KoyomiResult<Self> {
+        // 時差を先に足し、範囲外となる日本時間への変換を避ける
+        secs.checked_add(i64::from(JST_OFFSET))
+            .and_then(|s| DateTime::from_timestamp(s, 0))
+            .map(|dt| Date::from(dt.date_naive()))
+            .ok_or(KoyomiError::OutOfTimestamp(secs))
+    }
+
+    /// 和暦の年月日からオブジェクトを生成する
+    /// 指定した元号の期間外の日付はエラーとなる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Date;
+    ///
+    /// let date = Date::from_era("令和", 2, 5, 1).unwrap();
+    /// assert_eq!(date.to_string(), "2020-05-01");
+    ///
+    /// let date = Date::from_era("平成", 31, 5, 1);
+    /// assert!(date.is_err());
+    /// ```
+    pub fn from_era(name: &str, year: i32, month: u32, day: u32) -> KoyomiResult<Self> {
+        if !era::exists(name) {
+            return Err(KoyomiError::UnknownEra(name.into()));
+        }
+
+        let out = || KoyomiError::OutOfEra(name.into(), year, month, day);
+        let ad = era::ad(name, year).ok_or_else(out)?;
+        let date = Date::from_ymd(ad, month, day)?;
+        match date.era() {
+            Some(ref e) if e.name() == name => Ok(date),
+            _ => Err(out()),
+        }
+    }
+
+    /// 指定した月の第n X曜日(「第2月曜日」など)を返す
+    /// その月に第n X曜日がない場合はエラーとなる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Date, Weekday};
+    ///
+    /// let date = Date::nth_weekday(2018, 1, 2, Weekday::Monday).unwrap();
+    /// assert_eq!(date.to_string(), "2018-01-08");
+    ///
+    /// assert!(Date::nth_weekday(2018, 2, 5, Weekday::Monday).is_err());
+    /// ```
+    pub fn nth_weekday(year: i32, month: u32, n: u32, weekday: Weekday) -> KoyomiResult<Self> {
+        let head = Date::from_ymd(year, month, 1)?;
+        let none = || KoyomiError::NoNthWeekday(year, month, n, weekday);
+        if n == 0 || n > 5 {
+            return Err(none());
+        }
+
+        let day = 1 + weekday.days_since(head.weekday()) + (n - 1) * 7;
+        if day > num_days(year, month) {
+            return Err(none());
+        }
+        Ok(Date::from_days(head.days() + i64::from(day) - 1))
+    }
+
+    /// 指定した月の最終X曜日を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Date, Weekday};
+    ///
+    /// let date = Date::last_weekday(2018, 2, Weekday::Wednesday).unwrap();
+    /// assert_eq!(date.to_string(), "2018-02-28");
+    ///
+    /// let date = Date::last_weekday(2018, 2, Weekday::Friday).unwrap();
+    /// assert_eq!(date.to_string(), "2018-02-23");
+    /// ```
+    pub fn last_weekday(year: i32, month: u32, weekday: Weekday) -> KoyomiResult<Self> {
+        if !(1..=12).contains(&month) {
+            return Err(KoyomiError::InvalidMonth(month));
+        }
+
+        let last = Date::from_ymd(year, month, num_days(year, month))?;
+        Ok(Date::from_days(
+            last.days() - i64::from(last.weekday().days_since(weekday)),
+        ))
     }
 
     /// 「日」を返す
@@ -142,7 +592,7 @@ impl Date {
     /// assert_eq!(date.day(), 31);
     /// ```
     pub fn day(&self) -> u32 {
-        self.day
+        self.ymd().2
     }
 
     /// 「元号」を返す
@@ -159,6 +609,86 @@ impl Date {
         era::era(self)
     }
 
+    /// 「年度」を返す
+    /// 年度は4月始まりとなる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Date;
+    ///
+    /// let date = Date::from_ymd(2025, 3, 31).unwrap();
+    /// assert_eq!(date.fiscal_year().year(), 2024);
+    /// assert_eq!(date.fiscal_year().format(), "令和6年度");
+    /// ```
+    pub fn fiscal_year(&self) -> FiscalYear {
+        FiscalYear::of(self, FISCAL_START).expect("Invalid fiscal start!")
+    }
+
+    /// 始まりの月を指定して「年度」を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Date;
+    ///
+    /// let date = Date::from_ymd(2025, 3, 31).unwrap();
+    /// assert_eq!(date.fiscal_year_from(1).unwrap().year(), 2025);
+    /// assert!(date.fiscal_year_from(13).is_err());
+    /// ```
+    pub fn fiscal_year_from(&self, start: u32) -> KoyomiResult<FiscalYear> {
+        FiscalYear::of(self, start)
+    }
+
+    /// 4月始まりの年度における「上期・下期」を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Date, Half};
+    ///
+    /// let date = Date::from_ymd(2025, 3, 31).unwrap();
+    /// assert_eq!(date.fiscal_half(), Half::Second);
+    /// ```
+    pub fn fiscal_half(&self) -> Half {
+        self.fiscal_year().half(self).expect("Out of fiscal year!")
+    }
+
+    /// 4月始まりの年度における「四半期」を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Date;
+    ///
+    /// let date = Date::from_ymd(2025, 3, 31).unwrap();
+    /// assert_eq!(date.fiscal_quarter(), 4);
+    /// ```
+    pub fn fiscal_quarter(&self) -> u32 {
+        self.fiscal_year()
+            .quarter(self)
+            .expect("Out of fiscal year!")
+    }
+
+    /// 書式を指定して文字列にする
+    /// 書式の指定子は [`format`](format/index.html) を参照
+    /// 元号のない日付に `%E`・`%e` を指定した場合はエラーとなる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{format, Date};
+    ///
+    /// let date = Date::from_ymd(2019, 5, 1).unwrap();
+    /// assert_eq!(date.format(format::COMPACT).unwrap(), "20190501");
+    /// assert_eq!(date.format(format::KANJI).unwrap(), "2019年5月1日(水)");
+    /// assert_eq!(date.format(format::ERA).unwrap(), "令和元年5月1日");
+    /// assert_eq!(date.format("%#m/%#d").unwrap(), "０５/０１");
+    /// ```
+    pub fn format(&self, pattern: &str) -> KoyomiResult<String> {
+        format::format(self, pattern)
+    }
+
     /// 「祝祭日」を返す
     ///
     /// # Examples
@@ -176,6 +706,65 @@ impl Date {
         holiday::holiday(self)
     }
 
+    /// 「祝祭日」を指定した言語で返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Date, Locale};
+    ///
+    /// let date = Date::from_ymd(2018, 1, 8).unwrap();
+    /// assert_eq!(date.holiday_in(Locale::English).unwrap(), "Coming of Age Day");
+    /// assert_eq!(date.holiday_in(Locale::Kana).unwrap(), "せいじんのひ");
+    /// ```
+    pub fn holiday_in(&self, locale: Locale) -> Option<String> {
+        self.holiday()
+            .map(|name| locale.holiday(&name).map(String::from).unwrap_or(name))
+    }
+
+    /// 営業日(土曜・日曜・祝祭日以外)かどうかを判定する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Date;
+    ///
+    /// let date = Date::from_ymd(2018, 1, 4).unwrap();
+    /// assert!(date.is_business_day());
+    ///
+    /// // 土曜
+    /// let date = Date::from_ymd(2018, 1, 6).unwrap();
+    /// assert!(!date.is_business_day());
+    ///
+    /// // 成人の日
+    /// let date = Date::from_ymd(2018, 1, 8).unwrap();
+    /// assert!(!date.is_business_day());
+    /// ```
+    pub fn is_business_day(&self) -> bool {
+        match self.weekday() {
+            Saturday | Sunday => false,
+            _ => self.holiday().is_none(),
+        }
+    }
+
+    /// ISO 8601 の週を返す
+    /// 年始・年末の日付は、前年・翌年の週となることがある
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Date;
+    ///
+    /// let date = Date::from_ymd(2025, 2, 12).unwrap();
+    /// assert_eq!(date.iso_week().to_string(), "2025-W07");
+    ///
+    /// let date = Date::from_ymd(2021, 1, 1).unwrap();
+    /// assert_eq!(date.iso_week().to_string(), "2020-W53");
+    /// ```
+    pub fn iso_week(&self) -> IsoWeek {
+        IsoWeek::of(self)
+    }
+
     /// 「月」を返す
     ///
     /// # Examples
@@ -187,7 +776,29 @@ impl Date {
     /// assert_eq!(date.month(), 12);
     /// ```
     pub fn month(&self) -> u32 {
-        self.month
+        self.ymd().1
+    }
+
+    /// 翌日以降で最初に指定した曜日となる日付を返す
+    /// 自身と同じ曜日を指定した場合は1週間後となる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Date, Weekday};
+    ///
+    /// let date = Date::from_ymd(2018, 1, 1).unwrap();
+    /// let next = date.next_weekday(Weekday::Friday).unwrap();
+    /// assert_eq!(next.to_string(), "2018-01-05");
+    ///
+    /// let next = date.next_weekday(Weekday::Monday).unwrap();
+    /// assert_eq!(next.to_string(), "2018-01-08");
+    /// ```
+    pub fn next_weekday(&self, weekday: Weekday) -> KoyomiResult<Self> {
+        match weekday.days_since(self.weekday()) {
+            0 => self.shift(7),
+            n => self.shift(i64::from(n)),
+        }
     }
 
     /// 日付間の期間が何日あるかを返す
@@ -207,24 +818,29 @@ impl Date {
     /// assert_eq!(until.num_days(&from), 365);
     /// ```
     pub fn num_days(&self, date: &Date) -> i64 {
-        let min = NaiveDate::from_ymd(self.year, self.month, self.day);
-        let sub = NaiveDate::from_ymd(date.year(), date.month(), date.day());
-        min.signed_duration_since(sub).num_days()
+        self.days() - date.days()
     }
 
-    /// 日付の文字列表現を返す
-    /// フォーマットは `Y-m-d` 形式となる
+    /// 前日以前で最後に指定した曜日となる日付を返す
+    /// 自身と同じ曜日を指定した場合は1週間前となる
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use koyomi::Date;
+    /// use koyomi::{Date, Weekday};
     ///
     /// let date = Date::from_ymd(2018, 1, 1).unwrap();
-    /// assert_eq!(date.to_string(), "2018-01-01");
+    /// let prev = date.prev_weekday(Weekday::Friday).unwrap();
+    /// assert_eq!(prev.to_string(), "2017-12-29");
+    ///
+    /// let prev = date.prev_weekday(Weekday::Monday).unwrap();
+    /// assert_eq!(prev.to_string(), "2017-12-25");
     /// ```
-    pub fn to_string(&self) -> String {
-        format!("{:<4}-{:<02}-{:<02}", self.year, self.month, self.day)
+    pub fn prev_weekday(&self, weekday: Weekday) -> KoyomiResult<Self> {
+        match self.weekday().days_since(weekday) {
+            0 => self.shift(-7),
+            n => self.shift(-i64::from(n)),
+        }
     }
 
     /// 翌日の日付を返す
@@ -239,10 +855,48 @@ impl Date {
     /// assert_eq!(tomorrow.unwrap().to_string(), "2018-01-02");
     /// ```
     pub fn tomorrow(&self) -> KoyomiResult<Self> {
-        NaiveDate::from_ymd(self.year, self.month, self.day)
-            .succ_opt()
-            .ok_or(KoyomiError::NoTomorrow(self.year, self.month, self.day))
-            .map(|d| Date::from(d))
+        if *self == Date::MAX {
+            let (y, m, d) = self.ymd();
+            return Err(KoyomiError::NoTomorrow(y, m, d));
+        }
+        Ok(Date::from_days(self.days() + 1))
+    }
+
+    /// 月の何週目(1始まり)かを返す
+    /// 週の始まりの曜日を指定し、1日を含む週を第1週とする
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Date, Weekday};
+    ///
+    /// // 2018年9月1日は土曜
+    /// let date = Date::from_ymd(2018, 9, 2).unwrap();
+    /// assert_eq!(date.week_of_month(Weekday::Sunday), 2);
+    /// assert_eq!(date.week_of_month(Weekday::Monday), 1);
+    /// ```
+    pub fn week_of_month(&self, first: Weekday) -> u32 {
+        let head = Date::from_days(self.days() - i64::from(self.day()) + 1);
+        (head.weekday().days_since(first) + self.day() - 1) / 7 + 1
+    }
+
+    /// 年の何週目(1始まり)かを返す
+    /// 週の始まりの曜日を指定し、1月1日を含む週を第1週とする
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Date, Weekday};
+    ///
+    /// // 2025年1月1日は水曜
+    /// let date = Date::from_ymd(2025, 1, 5).unwrap();
+    /// assert_eq!(date.week_of_year(Weekday::Sunday), 2);
+    /// assert_eq!(date.week_of_year(Weekday::Monday), 1);
+    /// ```
+    pub fn week_of_year(&self, first: Weekday) -> u32 {
+        let new_year = Date::from_ymd(self.year(), 1, 1).expect("Invalid new year's day!");
+        let ordinal = self.num_days(&new_year) as u32 + 1;
+        (new_year.weekday().days_since(first) + ordinal - 1) / 7 + 1
     }
 
     /// 「曜日」を返す
@@ -253,10 +907,28 @@ impl Date {
     /// use koyomi::{Date, Weekday};
     ///
     /// let date = Date::from_ymd(2018, 1, 1).unwrap();
-    /// assert_eq!(date.weekday(), &Weekday::Monday);
+    /// assert_eq!(date.weekday(), Weekday::Monday);
     /// ```
-    pub fn weekday(&self) -> &Weekday {
-        &self.weekday
+    pub fn weekday(&self) -> Weekday {
+        weekday_of(self.days())
+    }
+
+    /// その月で何回目の曜日(第n X曜日のn)かを返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Date;
+    ///
+    /// // 第2月曜日
+    /// let date = Date::from_ymd(2018, 1, 8).unwrap();
+    /// assert_eq!(date.weekday_ordinal(), 2);
+    ///
+    /// let date = Date::from_ymd(2018, 1, 31).unwrap();
+    /// assert_eq!(date.weekday_ordinal(), 5);
+    /// ```
+    pub fn weekday_ordinal(&self) -> u32 {
+        (self.day() - 1) / 7 + 1
     }
 
     /// 「年」を返す
@@ -270,7 +942,7 @@ impl Date {
     /// assert_eq!(date.year(), 2018);
     /// ```
     pub fn year(&self) -> i32 {
-        self.year
+        self.ymd().0
     }
 
     /// 前日の日付を返す
@@ -285,54 +957,257 @@ impl Date {
     /// assert_eq!(yesterday.unwrap().to_string(), "2017-12-31");
     /// ```
     pub fn yesterday(&self) -> KoyomiResult<Self> {
-        NaiveDate::from_ymd(self.year, self.month, self.day)
-            .pred_opt()
-            .ok_or(KoyomiError::NoYesterday(self.year, self.month, self.day))
-            .map(|d| Date::from(d))
+        if *self == Date::MIN {
+            let (y, m, d) = self.ymd();
+            return Err(KoyomiError::NoYesterday(y, m, d));
+        }
+        Ok(Date::from_days(self.days() - 1))
+    }
+
+    /// 1970-01-01 からの日数から日付を生成する
+    fn from_days(days: i64) -> Self {
+        Date { days: days as i32 }
+    }
+
+    /// 指定した日数だけずらした日付を返す
+    /// 扱える範囲外となる場合はエラーとなる
+    pub(crate) fn shift(&self, days: i64) -> KoyomiResult<Self> {
+        let days = self.days() + days;
+        if days < Date::MIN.days() || Date::MAX.days() < days {
+            let (y, m, d) = civil_from_days(days);
+            return Err(KoyomiError::OutOfRange(y, m, d));
+        }
+        Ok(Date::from_days(days))
+    }
+
+    /// 1970-01-01 からの日数を返す
+    fn days(&self) -> i64 {
+        i64::from(self.days)
+    }
+
+    /// 年月日を返す
+    fn ymd(&self) -> (i32, u32, u32) {
+        civil_from_days(self.days())
+    }
+
+    /// 同じ日付の`chrono::NaiveDate`を返す
+    #[cfg(feature = "chrono")]
+    fn naive(&self) -> NaiveDate {
+        NaiveDate::from_num_days_from_ce_opt(self.days + CE_EPOCH_DAYS).expect("Invalid date!")
     }
 }
 
+#[cfg(feature = "chrono")]
 impl From<NaiveDate> for Date {
     fn from(date: NaiveDate) -> Self {
         Date {
-            year: date.year(),
-            month: date.month(),
-            day: date.day(),
-            weekday: Weekday::from(date.weekday()),
+            days: date.num_days_from_ce() - CE_EPOCH_DAYS,
         }
     }
 }
 
+#[cfg(feature = "chrono")]
+impl From<Date> for NaiveDate {
+    /// [`chrono::NaiveDate`]に変換する
+    ///
+    /// [`chrono::NaiveDate`]: https://docs.rs/chrono/0.4/chrono/naive/struct.NaiveDate.html
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi::Date;
+    ///
+    /// let date = Date::from_ymd(2018, 1, 1).unwrap();
+    /// let naive = NaiveDate::from(date);
+    /// assert_eq!(naive, NaiveDate::from_ymd_opt(2018, 1, 1).unwrap());
+    /// ```
+    fn from(date: Date) -> Self {
+        date.naive()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<&Date> for NaiveDate {
+    fn from(date: &Date) -> Self {
+        date.naive()
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<time::Date> for Date {
+    type Error = KoyomiError;
+
+    /// `time` クレートの日付から生成する
+    /// 扱える範囲外の年(`time` の `large-dates` で表せる年)の日付はエラーとなる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::convert::TryFrom;
+    /// use koyomi::Date;
+    ///
+    /// let date = time::Date::from_calendar_date(2018, time::Month::January, 1).unwrap();
+    /// assert_eq!(Date::try_from(date).unwrap().holiday().unwrap(), "元日");
+    /// ```
+    fn try_from(date: time::Date) -> KoyomiResult<Self> {
+        Date::from_ymd(
+            date.year(),
+            u8::from(date.month()) as u32,
+            date.day() as u32,
+        )
+    }
+}
+
+#[cfg(feature = "time")]
+impl TryFrom<Date> for time::Date {
+    type Error = KoyomiError;
+
+    /// `time` クレートの日付に変換する
+    /// `time` で表せない年の日付はエラーとなる
+    fn try_from(date: Date) -> KoyomiResult<Self> {
+        let (y, m, d) = date.ymd();
+        let out = || KoyomiError::OutOfRange(y, m, d);
+        let month = time::Month::try_from(m as u8).map_err(|_| out())?;
+        time::Date::from_calendar_date(y, month, d as u8).map_err(|_| out())
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl From<jiff::civil::Date> for Date {
+    /// `jiff` クレートの日付から生成する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Date;
+    ///
+    /// let date = jiff::civil::date(2019, 5, 1);
+    /// assert_eq!(Date::from(date).era().unwrap().name(), "令和");
+    /// ```
+    fn from(date: jiff::civil::Date) -> Self {
+        Date::from_ymd(date.year().into(), date.month() as u32, date.day() as u32)
+            .expect("Invalid jiff date!")
+    }
+}
+
+#[cfg(feature = "jiff")]
+impl TryFrom<Date> for jiff::civil::Date {
+    type Error = KoyomiError;
+
+    /// `jiff` クレートの日付に変換する
+    /// `jiff` で表せない年(-9999〜9999 の範囲外)の日付はエラーとなる
+    fn try_from(date: Date) -> KoyomiResult<Self> {
+        let (y, m, d) = date.ymd();
+        let out = || KoyomiError::OutOfRange(y, m, d);
+        let year = i16::try_from(y).map_err(|_| out())?;
+        jiff::civil::Date::new(year, m as i8, d as i8).map_err(|_| out())
+    }
+}
+
 impl fmt::Display for Date {
+    /// 日付の文字列表現を返す
+    /// フォーマットは `Y-m-d` 形式となり、年は4桁以上に0埋めする
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Date;
+    ///
+    /// let date = Date::from_ymd(2018, 1, 1).unwrap();
+    /// assert_eq!(date.to_string(), "2018-01-01");
+    ///
+    /// let date = Date::from_ymd(-44, 3, 15).unwrap();
+    /// assert_eq!(date.to_string(), "-0044-03-15");
+    /// ```
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.to_string())
+        let (y, m, d) = self.ymd();
+        let sign = if y < 0 { "-" } else { "" };
+        write!(f, "{}{:04}-{:02}-{:02}", sign, y.unsigned_abs(), m, d)
     }
 }
 
-impl Ord for Date {
-    /// 日付オブジェクト同士を比較可能にする
-    fn cmp(&self, other: &Date) -> Ordering {
-        match self.year.cmp(&other.year) {
-            Ordering::Equal => match self.month.cmp(&other.month) {
-                Ordering::Equal => self.day.cmp(&other.day),
-                ord => ord,
-            },
-            ord => ord,
-        }
+impl fmt::Debug for Date {
+    /// 日数ではなく年月日と曜日を表示する
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (year, month, day) = self.ymd();
+        f.debug_struct("Date")
+            .field("year", &year)
+            .field("month", &month)
+            .field("day", &day)
+            .field("weekday", &self.weekday())
+            .finish()
+    }
+}
+
+/// 日本標準時(UTC+9)
+#[cfg(feature = "chrono")]
+fn jst() -> FixedOffset {
+    FixedOffset::east_opt(JST_OFFSET).expect("Invalid JST offset!")
+}
+
+/// 年月日から 1970-01-01 からの日数を求める
+///
+/// 3月始まりの400年周期に分解して計算する(Howard Hinnant の days_from_civil)。
+const fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let y = year as i64 - (month <= 2) as i64;
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * DAYS_PER_ERA + doe - UNIX_EPOCH_DAYS
+}
+
+/// 1970-01-01 からの日数から年月日を求める
+///
+/// [`days_from_civil`] の逆変換。
+fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let z = days + UNIX_EPOCH_DAYS;
+    let era = z.div_euclid(DAYS_PER_ERA);
+    let doe = z - era * DAYS_PER_ERA;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = (mp + 2) % 12 + 1;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year as i32, month as u32, day as u32)
+}
+
+/// 1970-01-01 からの日数から曜日を求める
+/// 1970-01-01 は木曜日
+fn weekday_of(days: i64) -> Weekday {
+    nth_weekday(days + 3)
+}
+
+/// 月曜を0とした番号から曜日を返す
+/// 7以上や負の番号は、7で割った余りとして扱う
+pub(crate) fn nth_weekday(n: i64) -> Weekday {
+    WEEKDAYS[n.rem_euclid(7) as usize]
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Date {
+    /// `YYYY-MM-DD` 形式の文字列にする
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
     }
 }
 
-impl PartialOrd for Date {
-    /// 日付オブジェクト同士を比較可能にする
-    fn partial_cmp(&self, other: &Date) -> Option<Ordering> {
-        Some(self.cmp(&other))
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Date {
+    /// `YYYY-MM-DD` 形式の文字列から生成する
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Date::parse(&s)
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(&s), &"YYYY-MM-DD"))
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::naive::{MAX_DATE, MIN_DATE};
 
     #[test]
     fn parse_hyphen_format() {
@@ -349,6 +1224,30 @@ mod tests {
         assert!(Date::parse("2018 01 01").is_err());
     }
 
+    #[test]
+    fn reason_of_invalid_date() {
+        assert!(matches!(
+            Date::parse("2018 01 01"),
+            Err(KoyomiError::InvalidFormat(_))
+        ));
+        assert!(matches!(
+            Date::parse("2018-13-01"),
+            Err(KoyomiError::InvalidMonth(13))
+        ));
+        assert!(matches!(
+            Date::parse("2019/02/29"),
+            Err(KoyomiError::InvalidDay(2019, 2, 29))
+        ));
+        assert!(matches!(
+            Date::from_era("慶応", 1, 1, 1),
+            Err(KoyomiError::UnknownEra(_))
+        ));
+        assert!(matches!(
+            Date::from_era("令和", 1, 4, 30),
+            Err(KoyomiError::OutOfEra(_, 1, 4, 30))
+        ));
+    }
+
     #[test]
     fn valid_ymd() {
         assert!(Date::from_ymd(2018, 1, 1).is_ok());
@@ -373,12 +1272,54 @@ mod tests {
         assert_eq!(date.era().unwrap().name(), "平成");
     }
 
+    #[test]
+    fn fiscal_year_of_date() {
+        let date = Date::parse("2018-04-01").unwrap();
+        assert_eq!(date.fiscal_year().year(), 2018);
+        assert_eq!(date.fiscal_quarter(), 1);
+        assert_eq!(date.fiscal_half(), Half::First);
+
+        let date = Date::parse("2018-03-31").unwrap();
+        assert_eq!(date.fiscal_year().year(), 2017);
+        assert_eq!(date.fiscal_quarter(), 4);
+        assert_eq!(date.fiscal_half(), Half::Second);
+    }
+
     #[test]
     fn holiday_of_date() {
         let date = Date::parse("2018-12-23").unwrap();
         assert_eq!(date.holiday().unwrap(), "天皇誕生日");
     }
 
+    #[test]
+    fn date_from_era() {
+        let date = Date::from_era("令和", 1, 5, 1).unwrap();
+        assert_eq!(date.to_string(), "2019-05-01");
+
+        let date = Date::from_era("平成", 31, 4, 30).unwrap();
+        assert_eq!(date.to_string(), "2019-04-30");
+
+        assert!(Date::from_era("平成", 31, 5, 1).is_err());
+        assert!(Date::from_era("令和", 1, 4, 30).is_err());
+        assert!(Date::from_era("慶応", 1, 1, 1).is_err());
+
+        for &year in [i32::MAX, 0, -1, i32::MIN].iter() {
+            assert!(matches!(
+                Date::from_era("令和", year, 1, 1),
+                Err(KoyomiError::OutOfEra(_, y, 1, 1)) if y == year
+            ));
+        }
+    }
+
+    #[test]
+    fn business_day() {
+        assert!(Date::parse("2018-12-25").unwrap().is_business_day());
+        assert!(!Date::parse("2018-12-24").unwrap().is_business_day());
+        assert!(!Date::parse("2018-12-29").unwrap().is_business_day());
+        assert!(!Date::parse("2018-12-30").unwrap().is_business_day());
+    }
+
+    #[cfg(feature = "chrono")]
     #[test]
     fn monday_of_weekday() {
         let weekday = Weekday::from(ChronoWeekday::Mon);
@@ -386,6 +1327,7 @@ mod tests {
         assert_eq!(weekday.japanese(), '月');
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn tuesday_of_weekday() {
         let weekday = Weekday::from(ChronoWeekday::Tue);
@@ -393,6 +1335,7 @@ mod tests {
         assert_eq!(weekday.japanese(), '火');
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn wednesday_of_weekday() {
         let weekday = Weekday::from(ChronoWeekday::Wed);
@@ -400,6 +1343,7 @@ mod tests {
         assert_eq!(weekday.japanese(), '水');
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn thursday_of_weekday() {
         let weekday = Weekday::from(ChronoWeekday::Thu);
@@ -407,6 +1351,7 @@ mod tests {
         assert_eq!(weekday.japanese(), '木');
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn friday_of_weekday() {
         let weekday = Weekday::from(ChronoWeekday::Fri);
@@ -414,6 +1359,7 @@ mod tests {
         assert_eq!(weekday.japanese(), '金');
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn saturday_of_weekday() {
         let weekday = Weekday::from(ChronoWeekday::Sat);
@@ -421,6 +1367,7 @@ mod tests {
         assert_eq!(weekday.japanese(), '土');
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn sunday_of_weekday() {
         let weekday = Weekday::from(ChronoWeekday::Sun);
@@ -428,6 +1375,53 @@ mod tests {
         assert_eq!(weekday.japanese(), '日');
     }
 
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn weekday_into_chrono() {
+        for w in WEEKDAYS.iter() {
+            let chrono = ChronoWeekday::from(*w);
+            assert_eq!(Weekday::from(chrono), *w);
+            assert_eq!(chrono.number_from_monday(), w.number_from_monday());
+            assert_eq!(chrono.num_days_from_sunday(), w.num_days_from_sunday());
+        }
+    }
+
+    #[test]
+    fn weekday_succ_pred() {
+        let mut weekday = Monday;
+        for expected in WEEKDAYS.iter().cycle().skip(1).take(14) {
+            weekday = weekday.succ();
+            assert_eq!(weekday, *expected);
+            assert_eq!(weekday.succ().pred(), weekday);
+        }
+        assert_eq!(Saturday.succ(), Sunday);
+        assert_eq!(Sunday.pred(), Saturday);
+    }
+
+    #[test]
+    fn weekday_order() {
+        assert!(Monday < Tuesday && Saturday < Sunday);
+        assert_eq!(WEEKDAYS.iter().max(), Some(&Sunday));
+
+        let mut wall = WEEKDAYS;
+        wall.sort_by_key(|w| w.num_days_from_sunday());
+        let header = wall.iter().map(|w| w.to_string()).collect::<String>();
+        assert_eq!(header, "日月火水木金土");
+        assert_eq!(Friday.long_japanese(), "金曜日");
+    }
+
+    #[test]
+    fn weekday_from_str() {
+        let japanese = ["水", "水曜", "水曜日", "すい", "すいようび"];
+        let english = ["Wed", "wednesday", "WED"];
+        for s in japanese.iter().chain(english.iter()) {
+            assert_eq!(s.parse::<Weekday>().unwrap(), Wednesday, "{}", s);
+        }
+        for s in ["", "曜", "水曜曜", "We", "Wednesdays"].iter() {
+            assert!(s.parse::<Weekday>().is_err(), "{}", s);
+        }
+    }
+
     #[test]
     fn valid_tomorrow() {
         let date = Date::parse("2018-12-24").unwrap();
@@ -436,8 +1430,7 @@ mod tests {
 
     #[test]
     fn invalid_tomorrow() {
-        let date = Date::parse(&MAX_DATE.format("%Y-%m-%d").to_string()).unwrap();
-        assert!(date.tomorrow().is_err());
+        assert!(Date::MAX.tomorrow().is_err());
     }
 
     #[test]
@@ -448,8 +1441,7 @@ mod tests {
 
     #[test]
     fn invalid_yesterday() {
-        let date = Date::parse(&MIN_DATE.format("%Y-%m-%d").to_string()).unwrap();
-        assert!(date.yesterday().is_err());
+        assert!(Date::MIN.yesterday().is_err());
     }
 
     #[test]
@@ -466,6 +1458,95 @@ mod tests {
         assert_eq!(date.num_days(&sub), 0);
     }
 
+    #[test]
+    fn nth_and_last_weekday() {
+        for month in 1..=12 {
+            for w in WEEKDAYS.iter() {
+                let mut n = 1;
+                while let Ok(date) = Date::nth_weekday(2020, month, n, *w) {
+                    assert_eq!((date.month(), date.weekday()), (month, *w));
+                    assert_eq!(date.weekday_ordinal(), n);
+                    n += 1;
+                }
+                let last = Date::last_weekday(2020, month, *w).unwrap();
+                assert_eq!(Date::nth_weekday(2020, month, n - 1, *w).unwrap(), last);
+                assert!(n == 5 || n == 6);
+            }
+        }
+
+        let err = Date::nth_weekday(2018, 2, 5, Monday).unwrap_err();
+        assert_eq!(err.to_string(), "2018年2月に第5月曜日はありません");
+        assert_eq!(err.english(), "no Monday #5 in 2018-02");
+        assert!(Date::nth_weekday(2018, 1, 0, Monday).is_err());
+        assert!(matches!(
+            Date::nth_weekday(2018, 13, 1, Monday),
+            Err(KoyomiError::InvalidMonth(13))
+        ));
+        assert!(matches!(
+            Date::last_weekday(2018, 0, Monday),
+            Err(KoyomiError::InvalidMonth(0))
+        ));
+    }
+
+    #[test]
+    fn next_and_prev_weekday() {
+        // 2018-12-28 は金曜
+        let date = Date::from_ymd(2018, 12, 28).unwrap();
+        assert_eq!(date.next_weekday(Monday).unwrap().to_string(), "2018-12-31");
+        assert_eq!(
+            date.next_weekday(Tuesday).unwrap().to_string(),
+            "2019-01-01"
+        );
+        assert_eq!(date.next_weekday(Friday).unwrap().to_string(), "2019-01-04");
+        assert_eq!(
+            date.prev_weekday(Saturday).unwrap().to_string(),
+            "2018-12-22"
+        );
+        assert_eq!(date.prev_weekday(Friday).unwrap().to_string(), "2018-12-21");
+
+        // Date::MAX は月曜、Date::MIN は木曜
+        assert!(Date::MAX.next_weekday(Monday).is_err());
+        assert!(Date::MIN.prev_weekday(Thursday).is_err());
+        assert_eq!(
+            Date::MIN.next_weekday(Friday).unwrap(),
+            Date::MIN.tomorrow().unwrap()
+        );
+    }
+
+    #[test]
+    fn week_of_month() {
+        // 2018年9月1日は土曜、30日は日曜
+        let first = Date::from_ymd(2018, 9, 1).unwrap();
+        assert_eq!(first.week_of_month(Sunday), 1);
+        assert_eq!(first.week_of_month(Saturday), 1);
+
+        let last = Date::from_ymd(2018, 9, 30).unwrap();
+        assert_eq!(last.week_of_month(Sunday), 6);
+        assert_eq!(last.week_of_month(Monday), 5);
+
+        // 月表示の行と一致する
+        let grid = crate::MonthGrid::new(2018, 9, Sunday).unwrap();
+        for (row, week) in grid.weeks().iter().enumerate() {
+            for cell in week.iter().filter(|c| c.in_month()) {
+                assert_eq!(cell.date().week_of_month(Sunday), row as u32 + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn week_of_year() {
+        // 2023年1月1日は日曜、12月31日も日曜
+        let first = Date::from_ymd(2023, 1, 1).unwrap();
+        assert_eq!(first.week_of_year(Sunday), 1);
+        assert_eq!(first.week_of_year(Monday), 1);
+        assert_eq!(first.tomorrow().unwrap().week_of_year(Monday), 2);
+
+        let last = Date::from_ymd(2023, 12, 31).unwrap();
+        assert_eq!(last.week_of_year(Sunday), 53);
+        assert_eq!(last.week_of_year(Monday), 53);
+        assert_eq!(last.iso_week().to_string(), "2023-W52");
+    }
+
     #[test]
     fn date_to_string() {
         let format = "2018-01-01";
@@ -532,4 +1613,151 @@ mod tests {
         let d2 = Date::parse("2018-04-20").unwrap();
         assert!(d1 <= d2);
     }
+
+    #[test]
+    fn parse_signed_year() {
+        assert_eq!(
+            Date::parse("+2018-01-01").unwrap().to_string(),
+            "2018-01-01"
+        );
+        assert_eq!(Date::parse("-262143-01-01").unwrap(), Date::MIN);
+        assert!(matches!(
+            Date::parse("262143-01-01"),
+            Err(KoyomiError::OutOfRange(262143, 1, 1))
+        ));
+        assert!(Date::parse("2018-01-01-01").is_err());
+        assert!(Date::parse("2018-01/01").is_err());
+        assert!(Date::parse("2018--01").is_err());
+    }
+
+    #[test]
+    fn day_number_round_trip() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(days_from_civil(2000, 3, 1), 11017);
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+
+        let mut date = Date::from_ymd(1999, 12, 25).unwrap();
+        for _ in 0..800 {
+            let next = date.tomorrow().unwrap();
+            assert_eq!(next.num_days(&date), 1);
+            assert_eq!(Date::from_days(next.days()), next);
+            date = next;
+        }
+        assert_eq!(date.to_string(), "2002-03-04");
+        assert_eq!(date.weekday(), Monday);
+    }
+
+    #[test]
+    fn weekday_at_bounds() {
+        assert_eq!(Date::from_ymd(262142, 12, 31).unwrap(), Date::MAX);
+        assert_eq!(Date::from_ymd(-262143, 1, 1).unwrap(), Date::MIN);
+        assert_eq!(Date::MAX.yesterday().unwrap().weekday(), Sunday);
+        assert_eq!(Date::MIN.tomorrow().unwrap().weekday(), Friday);
+    }
+
+    #[test]
+    fn packed_date() {
+        use std::collections::HashSet;
+
+        assert_eq!(std::mem::size_of::<Date>(), 4);
+
+        let date = Date::from_ymd(2018, 1, 1).unwrap();
+        let copied = date;
+        assert_eq!(copied, date);
+        assert_eq!(
+            format!("{:?}", date),
+            "Date { year: 2018, month: 1, day: 1, weekday: Monday }"
+        );
+
+        let set = [date, copied, date.tomorrow().unwrap()]
+            .iter()
+            .copied()
+            .collect::<HashSet<_>>();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn naive_date_round_trip() {
+        let date = Date::parse("2019-05-01").unwrap();
+        let naive = NaiveDate::from(&date);
+        assert_eq!(naive, NaiveDate::from_ymd_opt(2019, 5, 1).unwrap());
+        assert_eq!(Date::from(naive), date);
+
+        let naive: NaiveDate = Date::from(NaiveDate::MAX).into();
+        assert_eq!(naive, NaiveDate::MAX);
+        assert_eq!(Date::from(NaiveDate::MAX), Date::MAX);
+        assert_eq!(Date::from(NaiveDate::MIN), Date::MIN);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_date_round_trip() {
+        let date = Date::parse("2019-05-01").unwrap();
+        let converted = time::Date::try_from(date).unwrap();
+        assert_eq!(converted.to_string(), "2019-05-01");
+        assert_eq!(Date::try_from(converted).unwrap(), date);
+
+        assert!(matches!(
+            time::Date::try_from(Date::MAX),
+            Err(KoyomiError::OutOfRange(..))
+        ));
+    }
+
+    #[cfg(feature = "jiff")]
+    #[test]
+    fn jiff_date_round_trip() {
+        let date = Date::parse("2019-05-01").unwrap();
+        let converted = jiff::civil::Date::try_from(date).unwrap();
+        assert_eq!(converted, jiff::civil::date(2019, 5, 1));
+        assert_eq!(Date::from(converted), date);
+
+        assert!(matches!(
+            jiff::civil::Date::try_from(Date::MIN),
+            Err(KoyomiError::OutOfRange(..))
+        ));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn date_in_jst() {
+        let jst = FixedOffset::east_opt(JST_OFFSET).unwrap();
+
+        // 日本時間 0:00〜9:00 は UTC では前日
+        let utc = Utc.with_ymd_and_hms(2018, 12, 22, 15, 0, 0).unwrap();
+        assert_eq!(Date::from_datetime(&utc).to_string(), "2018-12-23");
+        let utc = Utc.with_ymd_and_hms(2018, 12, 22, 14, 59, 59).unwrap();
+        assert_eq!(Date::from_datetime(&utc).to_string(), "2018-12-22");
+
+        let dt = jst.with_ymd_and_hms(2018, 12, 23, 0, 0, 0).unwrap();
+        assert_eq!(Date::from_datetime(&dt).to_string(), "2018-12-23");
+
+        // 他のタイムゾーン(UTC-5)
+        let est = FixedOffset::west_opt(5 * 3600).unwrap();
+        let dt = est.with_ymd_and_hms(2018, 12, 22, 10, 0, 0).unwrap();
+        assert_eq!(Date::from_datetime(&dt).to_string(), "2018-12-23");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn date_from_timestamp() {
+        assert_eq!(Date::from_timestamp(0).unwrap().to_string(), "1970-01-01");
+        assert_eq!(
+            Date::from_timestamp(15 * 3600).unwrap().to_string(),
+            "1970-01-02"
+        );
+        assert!(matches!(
+            Date::from_timestamp(i64::MIN),
+            Err(KoyomiError::OutOfTimestamp(i64::MIN))
+        ));
+
+        // 262142-12-31T14:59:59Z は日本時間で最終日、その1秒後は範囲外
+        let last = 8_210_266_844_399;
+        assert_eq!(Date::from_timestamp(last).unwrap(), Date::MAX);
+        assert!(matches!(
+            Date::from_timestamp(last + 1),
+            Err(KoyomiError::OutOfTimestamp(_))
+        ));
+    }
 }
@@ -2,10 +2,23 @@
 //!
 //! 西暦に対応する元号(和暦)の定義。
 //! 天皇即位日に元号が変わり、在位中は変わらない。
-use crate::Date;
+#[cfg(feature = "serde")]
+use core::convert::TryFrom;
+
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Date, Locale};
+
+/// 年月日
+type Ymd = (i32, u32, u32);
 
 /// 明治以降の和暦
-const ERA: [(&str, i32, u32, u32, Option<(i32, u32, u32)>); 5] = [
+const ERA: [(&str, i32, u32, u32, Option<Ymd>); 5] = [
     ("令和", 2019, 5, 1, None),
     ("平成", 1989, 1, 8, Some((2019, 4, 30))),
     ("昭和", 1926, 12, 25, Some((1989, 1, 7))),
@@ -50,10 +63,50 @@ pub fn era(date: &Date) -> Option<Era> {
     None
 }
 
+/// 明治以降の元号名かどうかを判定する
+pub fn exists(name: &str) -> bool {
+    ERA.iter().any(|e| e.0 == name)
+}
+
+/// 明治以降の元号名を新しい順に返す
+pub fn names() -> impl Iterator<Item = &'static str> {
+    ERA.iter().map(|e| e.0)
+}
+
+/// 和暦の年を西暦の年に変換する
+///
+/// 明治以降の元号のみ対象とし、元号の期間外の年は `None` となる。
+pub fn ad(name: &str, year: i32) -> Option<i32> {
+    ERA.iter()
+        .find(|e| e.0 == name)
+        .filter(|_| year >= 1)
+        .and_then(|&(_, y, _, _, until)| {
+            y.checked_add(year - 1)
+                .filter(|&ad| !matches!(until, Some((y, _, _)) if ad > y))
+        })
+}
+
+/// 元号名と和暦の年から元号を導出する
+#[cfg(feature = "serde")]
+fn find(name: &str, year: i32) -> Option<Era> {
+    let ad = ad(name, year)?;
+    let &(name, y, m, d, until) = ERA.iter().find(|e| e.0 == name)?;
+    Some(Era {
+        ad,
+        name: name.into(),
+        from: Date::from_ymd(y, m, d).expect("Invalid era date!"),
+        until: until.map(|(y, m, d)| Date::from_ymd(y, m, d).expect("Invalid until era date!")),
+    })
+}
+
 /// 元号
 ///
 /// https://ja.wikipedia.org/wiki/元号
-#[derive(Debug, Eq, PartialEq)]
+///
+/// シリアライズ時は元号名と和暦の年(`{"name":"令和","year":2}`)となる。
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "Wareki", try_from = "Wareki"))]
 pub struct Era {
     ad: i32,
     name: String,
@@ -61,6 +114,34 @@ pub struct Era {
     until: Option<Date>,
 }
 
+/// シリアライズ時の元号の表現
+#[cfg(feature = "serde")]
+#[derive(Deserialize, Serialize)]
+struct Wareki {
+    name: String,
+    year: i32,
+}
+
+#[cfg(feature = "serde")]
+impl From<Era> for Wareki {
+    fn from(era: Era) -> Self {
+        Wareki {
+            year: era.year(),
+            name: era.name,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Wareki> for Era {
+    type Error = String;
+
+    fn try_from(wareki: Wareki) -> Result<Self, Self::Error> {
+        find(&wareki.name, wareki.year)
+            .ok_or_else(|| format!("invalid era: {}{}", wareki.name, wareki.year))
+    }
+}
+
 impl Era {
     /// 元号名を返す
     ///
@@ -115,6 +196,51 @@ impl Era {
         }
     }
 
+    /// 元号名を指定した言語で返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Date, Locale};
+    ///
+    /// let e = Date::from_ymd(2020, 1, 1).unwrap().era().unwrap();
+    /// assert_eq!(e.name_in(Locale::English), "Reiwa");
+    /// assert_eq!(e.name_in(Locale::Kana), "れいわ");
+    /// ```
+    pub fn name_in(&self, locale: Locale) -> String {
+        locale
+            .era(&self.name)
+            .map(String::from)
+            .unwrap_or_else(|| self.name())
+    }
+
+    /// 元号の文字列表現を指定した言語で返す
+    /// 英語では初年度も「1」とする
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Date, Locale};
+    ///
+    /// let e = Date::from_ymd(2020, 1, 1).unwrap().era().unwrap();
+    /// assert_eq!(e.format_in(Locale::Japanese), "令和2年");
+    /// assert_eq!(e.format_in(Locale::English), "Reiwa 2");
+    /// assert_eq!(e.format_in(Locale::Kana), "れいわ2ねん");
+    ///
+    /// let e = Date::from_ymd(2019, 5, 1).unwrap().era().unwrap();
+    /// assert_eq!(e.format_in(Locale::English), "Reiwa 1");
+    /// assert_eq!(e.format_in(Locale::Kana), "れいわがんねん");
+    /// ```
+    pub fn format_in(&self, locale: Locale) -> String {
+        let name = self.name_in(locale);
+        match (locale, self.year()) {
+            (Locale::Japanese, _) => self.format(),
+            (Locale::English, y) => format!("{} {}", name, y),
+            (Locale::Kana, 1) => format!("{}がんねん", name),
+            (Locale::Kana, y) => format!("{}{}ねん", name, y),
+        }
+    }
+
     /// 日付けが自分の元号の範囲内かどうかを判定する
     fn is_match(&self, date: &Date) -> bool {
         match self.until {
@@ -263,6 +389,21 @@ mod tests {
         assert_eq!(era(&date).unwrap().format(), "令和2年");
     }
 
+    #[test]
+    fn ad_of_era() {
+        assert_eq!(ad("令和", 1), Some(2019));
+        assert_eq!(ad("平成", 31), Some(2019));
+        assert_eq!(ad("昭和", 64), Some(1989));
+        assert_eq!(ad("明治", 1), Some(1868));
+    }
+
+    #[test]
+    fn ad_out_of_era() {
+        assert!(ad("平成", 32).is_none());
+        assert!(ad("令和", 0).is_none());
+        assert!(ad("慶応", 1).is_none());
+    }
+
     #[test]
     fn era_unknown() {
         let date = Date::parse("1868-01-24").unwrap();
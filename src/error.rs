@@ -0,0 +1,217 @@
+//! # エラー
+//!
+//! クレート単位のエラー定義。
+//! `Display` は日本語のメッセージとなり、英語のメッセージも取得できる。
+use core::fmt;
+use core::num::ParseIntError;
+#[cfg(feature = "std")]
+use std::error::Error;
+#[cfg(feature = "std")]
+use std::io;
+
+use alloc::format;
+use alloc::string::String;
+
+use crate::{Date, Locale, Weekday};
+
+/// クレート単位の`Result`
+///
+/// 失敗時は `KoyomiError` を返す
+pub type KoyomiResult<T> = Result<T, KoyomiError>;
+
+/// クレート単位のエラーバリアント
+///
+/// # Examples
+///
+/// ```rust
+/// use koyomi::{Date, KoyomiError};
+///
+/// let err = Date::parse("2018-02-30").unwrap_err();
+/// assert!(matches!(err, KoyomiError::InvalidDay(2018, 2, 30)));
+/// assert_eq!(err.to_string(), "2018年2月30日は存在しません");
+/// assert_eq!(err.english(), "invalid day: 2018-02-30");
+/// ```
+#[derive(Debug)]
+pub enum KoyomiError {
+    /// 妥当な形式ではない
+    InvalidFormat(String),
+    /// 数値として解釈できない
+    InvalidNumber(String, ParseIntError),
+    /// ファイルの読み込みに失敗した
+    #[cfg(feature = "std")]
+    Io(io::Error),
+    /// 妥当な月ではない
+    InvalidMonth(u32),
+    /// 妥当な日ではない(年月は妥当)
+    InvalidDay(i32, u32, u32),
+    /// 対応していない元号
+    UnknownEra(String),
+    /// 対応していない言語
+    UnknownLocale(String),
+    /// 元号の期間外の日付
+    OutOfEra(String, i32, u32, u32),
+    /// 元号(明治以降)がない日付
+    NoEra(i32, u32, u32),
+    /// 扱える範囲(または変換先の型で表せる範囲)外の日付
+    OutOfRange(i32, u32, u32),
+    /// カレンダーの期間指定が妥当ではない
+    InvalidTerm(Date, Date),
+    /// カレンダーを生成するための指定が不足している
+    NotEnough,
+    /// 指定日の翌日は存在しない
+    NoTomorrow(i32, u32, u32),
+    /// 指定日の前日は存在しない
+    NoYesterday(i32, u32, u32),
+    /// 指定した月に第n X曜日は存在しない
+    NoNthWeekday(i32, u32, u32, Weekday),
+    /// 指定した年に第n週は存在しない
+    InvalidWeek(i32, u32),
+    /// 日付として扱える範囲外の UNIX 時間
+    OutOfTimestamp(i64),
+}
+
+impl KoyomiError {
+    /// エラーの日本語メッセージを返す
+    /// `Display` と同じとなる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Date;
+    ///
+    /// let err = Date::from_era("平成", 31, 5, 1).unwrap_err();
+    /// assert_eq!(err.japanese(), "平成31年5月1日は平成の期間外です");
+    /// ```
+    pub fn japanese(&self) -> String {
+        use self::KoyomiError::*;
+
+        match *self {
+            InvalidFormat(ref s) => format!("形式が正しくありません: {}", s),
+            InvalidNumber(ref s, _) => format!("数値ではありません: {}", s),
+            #[cfg(feature = "std")]
+            Io(_) => "ファイルを読み込めません".into(),
+            InvalidMonth(m) => format!("{}月は存在しません", m),
+            InvalidDay(y, m, d) => format!("{}年{}月{}日は存在しません", y, m, d),
+            UnknownEra(ref e) => format!("対応していない元号です: {}", e),
+            UnknownLocale(ref l) => format!("対応していない言語です: {}", l),
+            OutOfEra(ref e, y, m, d) => format!("{}{}年{}月{}日は{}の期間外です", e, y, m, d, e),
+            NoEra(y, m, d) => format!("{}年{}月{}日は元号の対象外です", y, m, d),
+            OutOfRange(y, m, d) => format!("{}年{}月{}日は扱える範囲外です", y, m, d),
+            InvalidTerm(ref f, ref u) => format!("期間が正しくありません: {} 〜 {}", f, u),
+            NotEnough => "カレンダーの期間が指定されていません".into(),
+            NoTomorrow(y, m, d) => format!("{}年{}月{}日の翌日は扱えません", y, m, d),
+            NoYesterday(y, m, d) => format!("{}年{}月{}日の前日は扱えません", y, m, d),
+            NoNthWeekday(y, m, n, w) => {
+                format!("{}年{}月に第{}{}はありません", y, m, n, w.long_japanese())
+            }
+            InvalidWeek(y, w) => format!("{}年に第{}週はありません", y, w),
+            OutOfTimestamp(s) => format!("UNIX時間が扱える範囲外です: {}", s),
+        }
+    }
+
+    /// エラーの英語メッセージを返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Date;
+    ///
+    /// let err = Date::from_era("平成", 31, 5, 1).unwrap_err();
+    /// assert_eq!(err.english(), "out of era: 平成 31, 5/1");
+    /// ```
+    pub fn english(&self) -> String {
+        use self::KoyomiError::*;
+
+        match *self {
+            InvalidFormat(ref s) => format!("invalid format: {}", s),
+            InvalidNumber(ref s, _) => format!("not a number: {}", s),
+            #[cfg(feature = "std")]
+            Io(_) => "failed to read file".into(),
+            InvalidMonth(m) => format!("invalid month: {}", m),
+            InvalidDay(y, m, d) => format!("invalid day: {:04}-{:02}-{:02}", y, m, d),
+            UnknownEra(ref e) => format!("unknown era: {}", e),
+            UnknownLocale(ref l) => format!("unknown locale: {}", l),
+            OutOfEra(ref e, y, m, d) => format!("out of era: {} {}, {}/{}", e, y, m, d),
+            NoEra(y, m, d) => format!("no era: {:04}-{:02}-{:02}", y, m, d),
+            OutOfRange(y, m, d) => format!("out of range: {:04}-{:02}-{:02}", y, m, d),
+            InvalidTerm(ref f, ref u) => format!("invalid term: {} to {}", f, u),
+            NotEnough => "calendar term is not specified".into(),
+            NoTomorrow(y, m, d) => format!("no date after {:04}-{:02}-{:02}", y, m, d),
+            NoYesterday(y, m, d) => format!("no date before {:04}-{:02}-{:02}", y, m, d),
+            NoNthWeekday(y, m, n, w) => format!(
+                "no {} #{} in {:04}-{:02}",
+                w.long_name_in(Locale::English),
+                n,
+                y,
+                m
+            ),
+            InvalidWeek(y, w) => format!("invalid week: {:04}-W{:02}", y, w),
+            OutOfTimestamp(s) => format!("timestamp out of range: {}", s),
+        }
+    }
+}
+
+impl fmt::Display for KoyomiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.japanese())
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for KoyomiError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            KoyomiError::InvalidNumber(_, ref e) => Some(e),
+            KoyomiError::Io(ref e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for KoyomiError {
+    fn from(err: io::Error) -> Self {
+        KoyomiError::Io(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_in_japanese() {
+        let err = KoyomiError::InvalidMonth(13);
+        assert_eq!(err.to_string(), "13月は存在しません");
+        assert_eq!(err.english(), "invalid month: 13");
+
+        let err = KoyomiError::NoTomorrow(262143, 12, 31);
+        assert_eq!(err.to_string(), "262143年12月31日の翌日は扱えません");
+        assert_eq!(err.english(), "no date after 262143-12-31");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn source_chain() {
+        let parse = "x".parse::<i32>().unwrap_err();
+        let err = KoyomiError::InvalidNumber("x".into(), parse);
+        assert!(err.source().is_some());
+
+        let err = KoyomiError::from(io::Error::new(io::ErrorKind::NotFound, "missing"));
+        assert_eq!(err.source().unwrap().to_string(), "missing");
+
+        assert!(KoyomiError::NotEnough.source().is_none());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn into_boxed_error() {
+        fn run() -> Result<(), Box<dyn Error>> {
+            Date::parse("2018-13-01")?;
+            Ok(())
+        }
+
+        let err = run().unwrap_err();
+        assert_eq!(err.to_string(), "13月は存在しません");
+    }
+}
@@ -0,0 +1,105 @@
+//! # 拡張トレイト
+//!
+//! `chrono` の日付型に祝祭日・和暦の判定を追加する。
+//! `Date` に変換せずに、`NaiveDate` や `DateTime<Tz>` のまま扱うことができる。
+use alloc::string::String;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::{Date, Era};
+
+/// 日本の暦に関する判定を追加する拡張トレイト
+///
+/// [`chrono::Datelike`] を実装するすべての型で使うことができる。
+/// 時刻を持つ型では、その型が表す日付(タイムゾーン適用後)で判定する。
+///
+/// [`chrono::Datelike`]: https://docs.rs/chrono/0.4/chrono/trait.Datelike.html
+///
+/// # Examples
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use koyomi::JapaneseCalendarExt;
+///
+/// let date = NaiveDate::from_ymd_opt(2019, 5, 1).unwrap();
+/// assert_eq!(date.jp_holiday().unwrap(), "新天皇即位日");
+/// assert_eq!(date.jp_era().unwrap().format(), "令和元年");
+/// assert!(!date.is_jp_business_day());
+/// ```
+pub trait JapaneseCalendarExt {
+    /// 祝祭日であればその名前を返す
+    fn jp_holiday(&self) -> Option<String>;
+
+    /// 和暦を返す
+    /// 明治以前は `None` となる
+    fn jp_era(&self) -> Option<Era>;
+
+    /// 営業日(土日・祝祭日以外)かどうかを判定する
+    fn is_jp_business_day(&self) -> bool;
+}
+
+impl<T: Datelike> JapaneseCalendarExt for T {
+    fn jp_holiday(&self) -> Option<String> {
+        date(self).holiday()
+    }
+
+    fn jp_era(&self) -> Option<Era> {
+        date(self).era()
+    }
+
+    fn is_jp_business_day(&self) -> bool {
+        date(self).is_business_day()
+    }
+}
+
+/// `Datelike` の年月日から日付を生成する
+fn date<T: Datelike>(datelike: &T) -> Date {
+    NaiveDate::from_ymd_opt(datelike.year(), datelike.month(), datelike.day())
+        .map(Date::from)
+        .expect("Invalid datelike!")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, TimeZone, Utc};
+
+    #[test]
+    fn naive_date() {
+        let date = NaiveDate::from_ymd_opt(2018, 1, 1).unwrap();
+        assert_eq!(date.jp_holiday().unwrap(), "元日");
+        assert_eq!(date.jp_era().unwrap().name(), "平成");
+        assert!(!date.is_jp_business_day());
+
+        let date = NaiveDate::from_ymd_opt(2018, 1, 4).unwrap();
+        assert!(date.jp_holiday().is_none());
+        assert!(date.is_jp_business_day());
+    }
+
+    #[test]
+    fn naive_date_time() {
+        let dt = NaiveDate::from_ymd_opt(2019, 4, 30)
+            .unwrap()
+            .and_hms_opt(23, 59, 59)
+            .unwrap();
+        assert_eq!(dt.jp_era().unwrap().name(), "平成");
+        assert_eq!(dt.jp_holiday().unwrap(), "国民の休日");
+    }
+
+    #[test]
+    fn date_time_with_time_zone() {
+        // UTC では1月1日 15時、日本時間では1月2日 0時
+        let utc = Utc.with_ymd_and_hms(2018, 1, 1, 15, 0, 0).unwrap();
+        assert_eq!(utc.jp_holiday().unwrap(), "元日");
+
+        let jst = utc.with_timezone(&FixedOffset::east_opt(9 * 3600).unwrap());
+        assert!(jst.jp_holiday().is_none());
+    }
+
+    #[test]
+    fn before_meiji() {
+        let date = NaiveDate::from_ymd_opt(1800, 1, 1).unwrap();
+        assert!(date.jp_era().is_none());
+        assert!(date.jp_holiday().is_none());
+    }
+}
@@ -0,0 +1,413 @@
+//! # 年度
+//!
+//! 4月から翌年3月までを1年とする年度の定義。
+//! 年度は始まりの月が属する年で表す(2024年4月〜2025年3月は2024年度)。
+#[cfg(feature = "serde")]
+use core::convert::TryFrom;
+
+use alloc::format;
+use alloc::string::String;
+#[cfg(feature = "serde")]
+use alloc::string::ToString;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::calendar::num_days;
+use crate::{Calendar, Date, Era, KoyomiError, KoyomiResult};
+
+/// 年度が始まる標準の月
+pub const FISCAL_START: u32 = 4;
+
+/// 1四半期が何ヶ月か
+const QUARTER_MONTHS: u32 = 3;
+
+/// 上期・下期
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Half {
+    /// 上期
+    First,
+    /// 下期
+    Second,
+}
+
+impl Half {
+    /// 上期・下期の日本語表現を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Half;
+    ///
+    /// assert_eq!(Half::First.japanese(), "上期");
+    /// assert_eq!(Half::Second.japanese(), "下期");
+    /// ```
+    pub fn japanese(&self) -> &'static str {
+        match *self {
+            Half::First => "上期",
+            Half::Second => "下期",
+        }
+    }
+}
+
+/// 年度
+///
+/// 始まりの月は標準で4月だが、任意の月を指定することもできる。
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Fiscal"))]
+pub struct FiscalYear {
+    year: i32,
+    start: u32,
+}
+
+/// デシリアライズ時に始まりの月を検証するための中間表現
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct Fiscal {
+    year: i32,
+    start: u32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Fiscal> for FiscalYear {
+    type Error = String;
+
+    fn try_from(fiscal: Fiscal) -> Result<Self, Self::Error> {
+        FiscalYear::with_start(fiscal.year, fiscal.start).map_err(|e| e.to_string())
+    }
+}
+
+impl FiscalYear {
+    /// 4月始まりの年度を生成する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::FiscalYear;
+    ///
+    /// let fy = FiscalYear::new(2024);
+    /// assert_eq!(fy.first_day().unwrap().to_string(), "2024-04-01");
+    /// assert_eq!(fy.last_day().unwrap().to_string(), "2025-03-31");
+    /// ```
+    pub fn new(year: i32) -> Self {
+        FiscalYear {
+            year,
+            start: FISCAL_START,
+        }
+    }
+
+    /// 始まりの月を指定して年度を生成する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::FiscalYear;
+    ///
+    /// let fy = FiscalYear::with_start(2024, 10);
+    /// assert!(fy.is_ok());
+    ///
+    /// let fy = FiscalYear::with_start(2024, 13);
+    /// assert!(fy.is_err());
+    /// ```
+    pub fn with_start(year: i32, start: u32) -> KoyomiResult<Self> {
+        if (1..=12).contains(&start) {
+            Ok(FiscalYear { year, start })
+        } else {
+            Err(KoyomiError::InvalidMonth(start))
+        }
+    }
+
+    /// 日付が属する年度を導出する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Date, FiscalYear};
+    ///
+    /// let date = Date::from_ymd(2025, 3, 31).unwrap();
+    /// let fy = FiscalYear::of(&date, 4).unwrap();
+    /// assert_eq!(fy.year(), 2024);
+    /// ```
+    pub fn of(date: &Date, start: u32) -> KoyomiResult<Self> {
+        let year = if date.month() >= start {
+            date.year()
+        } else {
+            date.year() - 1
+        };
+        FiscalYear::with_start(year, start)
+    }
+
+    /// 年度の呼称となる年を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::FiscalYear;
+    ///
+    /// assert_eq!(FiscalYear::new(2024).year(), 2024);
+    /// ```
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// 年度が始まる月を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::FiscalYear;
+    ///
+    /// assert_eq!(FiscalYear::new(2024).start_month(), 4);
+    /// ```
+    pub fn start_month(&self) -> u32 {
+        self.start
+    }
+
+    /// 年度の初日を返す
+    /// 扱える範囲外の日付となる場合はエラーとなる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::FiscalYear;
+    ///
+    /// let fy = FiscalYear::with_start(2024, 1).unwrap();
+    /// assert_eq!(fy.first_day().unwrap().to_string(), "2024-01-01");
+    ///
+    /// assert!(FiscalYear::new(300000).first_day().is_err());
+    /// ```
+    pub fn first_day(&self) -> KoyomiResult<Date> {
+        Date::from_ymd(self.year, self.start, 1)
+    }
+
+    /// 年度の末日を返す
+    /// 扱える範囲外の日付となる場合はエラーとなる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::FiscalYear;
+    ///
+    /// let fy = FiscalYear::with_start(2024, 1).unwrap();
+    /// assert_eq!(fy.last_day().unwrap().to_string(), "2024-12-31");
+    ///
+    /// assert!(FiscalYear::new(262142).last_day().is_err());
+    /// ```
+    pub fn last_day(&self) -> KoyomiResult<Date> {
+        let (year, month) = match self.start {
+            1 => (Some(self.year), 12),
+            start => (self.year.checked_add(1), start - 1),
+        };
+        let year = year.ok_or(KoyomiError::OutOfRange(self.year, self.start, 1))?;
+        Date::from_ymd(year, month, num_days(year, month))
+    }
+
+    /// 年度の期間のカレンダーを返す
+    /// 初日か末日が扱える範囲外となる場合はエラーとなる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::FiscalYear;
+    ///
+    /// let cal = FiscalYear::new(2024).calendar().unwrap();
+    /// assert_eq!(cal.from(), "2024-04-01");
+    /// assert_eq!(cal.until(), "2025-03-31");
+    /// ```
+    pub fn calendar(&self) -> KoyomiResult<Calendar> {
+        Calendar::new(self.first_day()?, self.last_day()?)
+    }
+
+    /// 年度の初日時点の元号を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::FiscalYear;
+    ///
+    /// let era = FiscalYear::new(2024).era().unwrap();
+    /// assert_eq!(era.name(), "令和");
+    /// ```
+    pub fn era(&self) -> Option<Era> {
+        self.first_day().ok()?.era()
+    }
+
+    /// 年度の和暦表現を返す
+    /// 元号は年度の初日で判定するため、2019年度は「平成31年度」となる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::FiscalYear;
+    ///
+    /// assert_eq!(FiscalYear::new(2024).format(), "令和6年度");
+    /// assert_eq!(FiscalYear::new(2020).format(), "令和2年度");
+    /// assert_eq!(FiscalYear::new(2019).format(), "平成31年度");
+    /// ```
+    pub fn format(&self) -> String {
+        match self.era() {
+            Some(era) => format!("{}度", era.format()),
+            None => format!("{}年度", self.year),
+        }
+    }
+
+    /// 日付が年度の第何四半期にあたるかを返す
+    /// 年度外の日付は `None` となる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Date, FiscalYear};
+    ///
+    /// let fy = FiscalYear::new(2024);
+    ///
+    /// let date = Date::from_ymd(2024, 4, 1).unwrap();
+    /// assert_eq!(fy.quarter(&date), Some(1));
+    ///
+    /// let date = Date::from_ymd(2025, 3, 31).unwrap();
+    /// assert_eq!(fy.quarter(&date), Some(4));
+    ///
+    /// let date = Date::from_ymd(2024, 3, 31).unwrap();
+    /// assert_eq!(fy.quarter(&date), None);
+    /// ```
+    pub fn quarter(&self, date: &Date) -> Option<u32> {
+        if !self.contains(date) {
+            return None;
+        }
+
+        let elapsed = (date.month() + 12 - self.start) % 12;
+        Some(elapsed / QUARTER_MONTHS + 1)
+    }
+
+    /// 日付が年度の上期・下期のどちらにあたるかを返す
+    /// 年度外の日付は `None` となる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Date, FiscalYear, Half};
+    ///
+    /// let fy = FiscalYear::new(2024);
+    ///
+    /// let date = Date::from_ymd(2024, 9, 30).unwrap();
+    /// assert_eq!(fy.half(&date), Some(Half::First));
+    ///
+    /// let date = Date::from_ymd(2024, 10, 1).unwrap();
+    /// assert_eq!(fy.half(&date), Some(Half::Second));
+    /// ```
+    pub fn half(&self, date: &Date) -> Option<Half> {
+        self.quarter(date)
+            .map(|q| if q <= 2 { Half::First } else { Half::Second })
+    }
+
+    /// 日付が年度内かどうかを判定する
+    /// 年度の初日・末日が範囲外となる年度でも判定できるよう、年月で比較する
+    fn contains(&self, date: &Date) -> bool {
+        let year = if date.month() >= self.start {
+            date.year()
+        } else {
+            date.year() - 1
+        };
+        year == self.year
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fiscal_year_of_date() {
+        let date = Date::parse("2024-04-01").unwrap();
+        assert_eq!(FiscalYear::of(&date, 4).unwrap().year(), 2024);
+
+        let date = Date::parse("2024-03-31").unwrap();
+        assert_eq!(FiscalYear::of(&date, 4).unwrap().year(), 2023);
+
+        let date = Date::parse("2024-03-31").unwrap();
+        assert_eq!(FiscalYear::of(&date, 1).unwrap().year(), 2024);
+    }
+
+    #[test]
+    fn invalid_start_month() {
+        assert!(FiscalYear::with_start(2024, 0).is_err());
+        assert!(FiscalYear::with_start(2024, 13).is_err());
+    }
+
+    #[test]
+    fn term_of_fiscal_year() {
+        let fy = FiscalYear::new(2023);
+        assert_eq!(fy.first_day().unwrap().to_string(), "2023-04-01");
+        assert_eq!(fy.last_day().unwrap().to_string(), "2024-03-31");
+
+        let fy = FiscalYear::with_start(2023, 10).unwrap();
+        assert_eq!(fy.first_day().unwrap().to_string(), "2023-10-01");
+        assert_eq!(fy.last_day().unwrap().to_string(), "2024-09-30");
+
+        let fy = FiscalYear::with_start(2024, 3).unwrap();
+        assert_eq!(fy.last_day().unwrap().to_string(), "2025-02-28");
+    }
+
+    #[test]
+    fn term_beyond_range() {
+        let fy = FiscalYear::with_start(262142, 1).unwrap();
+        assert_eq!(fy.last_day().unwrap(), Date::MAX);
+        assert!(fy.calendar().is_ok());
+
+        let fy = FiscalYear::new(262142);
+        assert!(fy.first_day().is_ok());
+        assert!(fy.last_day().is_err());
+        assert!(fy.calendar().is_err());
+
+        let fy = FiscalYear::new(-262144);
+        assert!(fy.first_day().is_err());
+        assert!(fy.era().is_none());
+        assert_eq!(fy.format(), "-262144年度");
+
+        for &year in &[i32::MIN, i32::MAX] {
+            let fy = FiscalYear::new(year);
+            assert!(fy.first_day().is_err());
+            assert!(fy.last_day().is_err());
+        }
+    }
+
+    #[test]
+    fn format_fiscal_year() {
+        assert_eq!(FiscalYear::new(1988).format(), "昭和63年度");
+        assert_eq!(FiscalYear::new(1989).format(), "平成元年度");
+        assert_eq!(FiscalYear::new(2019).format(), "平成31年度");
+        assert_eq!(FiscalYear::new(2020).format(), "令和2年度");
+        assert_eq!(FiscalYear::new(1800).format(), "1800年度");
+    }
+
+    #[test]
+    fn quarter_of_fiscal_year() {
+        let fy = FiscalYear::new(2024);
+        assert_eq!(fy.quarter(&Date::parse("2024-06-30").unwrap()), Some(1));
+        assert_eq!(fy.quarter(&Date::parse("2024-07-01").unwrap()), Some(2));
+        assert_eq!(fy.quarter(&Date::parse("2024-12-31").unwrap()), Some(3));
+        assert_eq!(fy.quarter(&Date::parse("2025-01-01").unwrap()), Some(4));
+        assert_eq!(fy.quarter(&Date::parse("2025-04-01").unwrap()), None);
+
+        // 初日・末日が範囲外の年度でも判定できる
+        assert_eq!(Date::MAX.fiscal_quarter(), 3);
+        assert_eq!(Date::MIN.fiscal_quarter(), 4);
+        assert_eq!(Date::MIN.fiscal_half(), Half::Second);
+    }
+
+    #[test]
+    fn half_of_fiscal_year() {
+        let fy = FiscalYear::with_start(2024, 1).unwrap();
+        assert_eq!(
+            fy.half(&Date::parse("2024-06-30").unwrap()),
+            Some(Half::First)
+        );
+        assert_eq!(
+            fy.half(&Date::parse("2024-07-01").unwrap()),
+            Some(Half::Second)
+        );
+        assert_eq!(fy.half(&Date::parse("2025-01-01").unwrap()), None);
+    }
+}
@@ -0,0 +1,442 @@
+//! # 書式
+//!
+//! `%` で始まる指定子による日付の書式化と解析。
+//! [`Date::format`](../struct.Date.html#method.format) で書式化した文字列は、
+//! 同じ書式の [`Date::parse_from_str`](../struct.Date.html#method.parse_from_str) で元の日付に戻る。
+//!
+//! | 指定子 | 意味 | 例 |
+//! |--------|------|----|
+//! | `%Y` | 年(4桁以上) | `2025` |
+//! | `%m` | 月(2桁) | `05` |
+//! | `%d` | 日(2桁) | `01` |
+//! | `%E` | 元号 | `令和` |
+//! | `%e` | 和暦の年(初年は「元」) | `7` |
+//! | `%a` | 曜日 | `木` |
+//! | `%%` | `%` そのもの | `%` |
+//!
+//! `%` の直後に `-` を付けると0埋めせず(`%-m` は `5`)、
+//! `#` を付けると全角数字(`%#d` は `０１`)となる。
+//! 解析時は0埋めの有無を問わず、全角数字も半角数字と同じに扱う。
+//!
+//! # Examples
+//!
+//! ```rust
+//! use koyomi::{format, Date};
+//!
+//! let date = Date::from_ymd(2025, 5, 1).unwrap();
+//! assert_eq!(date.format(format::KANJI).unwrap(), "2025年5月1日(木)");
+//! assert_eq!(date.format(format::ERA).unwrap(), "令和7年5月1日");
+//! assert_eq!(date.format("%#Y年%#-m月%#-d日").unwrap(), "２０２５年５月１日");
+//!
+//! let parsed = Date::parse_from_str("令和7年5月1日", format::ERA).unwrap();
+//! assert_eq!(parsed, date);
+//! ```
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::era;
+use crate::{Date, KoyomiError, KoyomiResult, Weekday};
+
+/// `2025-05-01`
+pub const ISO: &str = "%Y-%m-%d";
+
+/// `2025/05/01`
+pub const SLASH: &str = "%Y/%m/%d";
+
+/// `20250501`
+pub const COMPACT: &str = "%Y%m%d";
+
+/// `2025年5月1日(木)`
+pub const KANJI: &str = "%Y年%-m月%-d日(%a)";
+
+/// `令和7年5月1日`
+pub const ERA: &str = "%E%e年%-m月%-d日";
+
+/// `Date::parse` が日本語の日付として試す書式
+const JAPANESE: [&str; 4] = [KANJI, "%Y年%-m月%-d日", ERA, "%E%e年%-m月%-d日(%a)"];
+
+/// 指定子の種類
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Spec {
+    Year,
+    Month,
+    Day,
+    EraName,
+    EraYear,
+    Weekday,
+}
+
+impl Spec {
+    /// 数字で表す指定子かどうか
+    fn is_numeric(self) -> bool {
+        matches!(self, Spec::Year | Spec::Month | Spec::Day | Spec::EraYear)
+    }
+
+    /// 解析時に読み取る最大の桁数
+    fn max_digits(self) -> usize {
+        match self {
+            Spec::Year => 9,
+            _ => 2,
+        }
+    }
+
+    /// 区切りなしで続く場合(`%Y%m%d`)の桁数
+    fn packed_digits(self) -> usize {
+        match self {
+            Spec::Year => 4,
+            _ => 2,
+        }
+    }
+}
+
+/// 書式の要素
+#[derive(Clone, Copy, Debug)]
+enum Item {
+    /// そのまま出力・照合する文字
+    Literal(char),
+    /// 指定子と、0埋めするか・全角数字とするか
+    Field { spec: Spec, pad: bool, wide: bool },
+}
+
+/// 解析中に読み取った値
+#[derive(Default)]
+struct Fields {
+    year: Option<i32>,
+    month: Option<u32>,
+    day: Option<u32>,
+    era_name: Option<&'static str>,
+    era_year: Option<i32>,
+    weekday: Option<Weekday>,
+}
+
+/// 書式に従って日付を文字列にする
+pub(crate) fn format(date: &Date, pattern: &str) -> KoyomiResult<String> {
+    let mut out = String::new();
+    for item in items(pattern)? {
+        let (spec, pad, wide) = match item {
+            Item::Literal(c) => {
+                out.push(c);
+                continue;
+            }
+            Item::Field { spec, pad, wide } => (spec, pad, wide),
+        };
+
+        let text = match spec {
+            Spec::Year => {
+                let y = date.year();
+                let digits = number(y.unsigned_abs(), if pad { 4 } else { 1 });
+                if y < 0 {
+                    format!("-{}", digits)
+                } else {
+                    digits
+                }
+            }
+            Spec::Month => number(date.month(), if pad { 2 } else { 1 }),
+            Spec::Day => number(date.day(), if pad { 2 } else { 1 }),
+            Spec::EraName => era_of(date)?.name(),
+            Spec::EraYear => match era_of(date)?.year() {
+                1 => "元".into(),
+                y => y.to_string(),
+            },
+            Spec::Weekday => date.weekday().japanese().to_string(),
+        };
+        if wide {
+            out.extend(text.chars().map(widen));
+        } else {
+            out.push_str(&text);
+        }
+    }
+    Ok(out)
+}
+
+/// 書式に従って文字列から日付を生成する
+pub(crate) fn parse(s: &str, pattern: &str) -> KoyomiResult<Date> {
+    let invalid = || KoyomiError::InvalidFormat(s.into());
+    let items = items(pattern)?;
+    let input = s.chars().map(narrow).collect::<String>();
+    let mut rest = input.as_str();
+    let mut fields = Fields::default();
+
+    for (i, item) in items.iter().enumerate() {
+        let spec = match *item {
+            Item::Literal(c) => {
+                rest = rest.strip_prefix(c).ok_or_else(invalid)?;
+                continue;
+            }
+            Item::Field { spec, .. } => spec,
+        };
+        // 区切りなしで数字が続く場合は、後の指定子の桁数を残して読み取る
+        let reserve = items[i + 1..]
+            .iter()
+            .map_while(|item| match *item {
+                Item::Field { spec, .. } if spec.is_numeric() => Some(spec.packed_digits()),
+                _ => None,
+            })
+            .sum::<usize>();
+        let max = spec.max_digits();
+
+        match spec {
+            Spec::Year => {
+                let (sign, body) = match rest.strip_prefix('-') {
+                    Some(body) => (-1, body),
+                    None => (1, rest.strip_prefix('+').unwrap_or(rest)),
+                };
+                let (n, r) = digits(body, max, reserve).ok_or_else(invalid)?;
+                fields.year = Some(sign * n as i32);
+                rest = r;
+            }
+            Spec::Month => {
+                let (n, r) = digits(rest, max, reserve).ok_or_else(invalid)?;
+                fields.month = Some(n);
+                rest = r;
+            }
+            Spec::Day => {
+                let (n, r) = digits(rest, max, reserve).ok_or_else(invalid)?;
+                fields.day = Some(n);
+                rest = r;
+            }
+            Spec::EraName => {
+                let name = era::names()
+                    .find(|name| rest.starts_with(name))
+                    .ok_or_else(invalid)?;
+                fields.era_name = Some(name);
+                rest = &rest[name.len()..];
+            }
+            Spec::EraYear => {
+                let (n, r) = match rest.strip_prefix('元') {
+                    Some(r) => (1, r),
+                    None => digits(rest, max, reserve).ok_or_else(invalid)?,
+                };
+                fields.era_year = Some(n as i32);
+                rest = r;
+            }
+            Spec::Weekday => {
+                let c = rest.chars().next().ok_or_else(invalid)?;
+                let weekday = c.to_string().parse::<Weekday>().map_err(|_| invalid())?;
+                fields.weekday = Some(weekday);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    if !rest.is_empty() {
+        return Err(invalid());
+    }
+
+    let month = fields.month.ok_or_else(invalid)?;
+    let day = fields.day.ok_or_else(invalid)?;
+    let date = match (fields.year, fields.era_name, fields.era_year) {
+        (Some(year), None, None) => Date::from_ymd(year, month, day)?,
+        (year, Some(name), Some(era_year)) => {
+            let date = Date::from_era(name, era_year, month, day)?;
+            if matches!(year, Some(y) if y != date.year()) {
+                return Err(invalid());
+            }
+            date
+        }
+        _ => return Err(invalid()),
+    };
+    if matches!(fields.weekday, Some(w) if w != date.weekday()) {
+        return Err(invalid());
+    }
+    Ok(date)
+}
+
+/// 日本語の日付(`2025年5月1日(木)`・`令和7年5月1日` など)や、
+/// 全角数字を含む日付を解析する
+pub(crate) fn parse_japanese(s: &str) -> KoyomiResult<Date> {
+    let narrowed = s.chars().map(narrow).collect::<String>();
+    if narrowed.is_ascii() {
+        return Date::parse(&narrowed);
+    }
+
+    // 書式に合わないもの以外(存在しない日付など)は、そのエラーを返す
+    JAPANESE
+        .iter()
+        .map(|pattern| parse(s, pattern))
+        .find(|r| !matches!(r, Err(KoyomiError::InvalidFormat(_))))
+        .unwrap_or_else(|| Err(KoyomiError::InvalidFormat(s.into())))
+}
+
+/// 書式を要素に分解する
+fn items(pattern: &str) -> KoyomiResult<Vec<Item>> {
+    let invalid = || KoyomiError::InvalidFormat(pattern.into());
+    let mut items = Vec::new();
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            items.push(Item::Literal(c));
+            continue;
+        }
+
+        let (mut pad, mut wide) = (true, false);
+        let spec = loop {
+            match chars.next().ok_or_else(invalid)? {
+                '-' => pad = false,
+                '#' => wide = true,
+                '%' => break None,
+                'Y' => break Some(Spec::Year),
+                'm' => break Some(Spec::Month),
+                'd' => break Some(Spec::Day),
+                'E' => break Some(Spec::EraName),
+                'e' => break Some(Spec::EraYear),
+                'a' => break Some(Spec::Weekday),
+                _ => return Err(invalid()),
+            }
+        };
+        items.push(match spec {
+            Some(spec) => Item::Field { spec, pad, wide },
+            None => Item::Literal('%'),
+        });
+    }
+    Ok(items)
+}
+
+/// 日付の元号を返す
+fn era_of(date: &Date) -> KoyomiResult<era::Era> {
+    date.era()
+        .ok_or_else(|| KoyomiError::NoEra(date.year(), date.month(), date.day()))
+}
+
+/// 数値を指定した桁数まで0埋めする
+fn number(n: u32, width: usize) -> String {
+    format!("{:0width$}", n, width = width)
+}
+
+/// 先頭から最大 `max` 桁の数字を読み取る
+/// 後に続く数字のために、連続する数字の末尾 `reserve` 桁は残す
+fn digits(s: &str, max: usize, reserve: usize) -> Option<(u32, &str)> {
+    let run = s.bytes().take_while(u8::is_ascii_digit).count();
+    let len = match reserve {
+        0 => run.min(max),
+        _ => run.saturating_sub(reserve).clamp(1, max).min(run),
+    };
+    let n = s[..len].parse().ok()?;
+    Some((n, &s[len..]))
+}
+
+/// 半角数字を全角数字にする
+fn widen(c: char) -> char {
+    match c {
+        '0'..='9' => char::from_u32(u32::from(c) - 0x30 + 0xFF10).unwrap_or(c),
+        _ => c,
+    }
+}
+
+/// 全角数字を半角数字にする
+fn narrow(c: char) -> char {
+    match c {
+        '０'..='９' => char::from_u32(u32::from(c) - 0xFF10 + 0x30).unwrap_or(c),
+        _ => c,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_directives() {
+        let date = Date::from_ymd(2019, 5, 1).unwrap();
+        assert_eq!(format(&date, ISO).unwrap(), "2019-05-01");
+        assert_eq!(format(&date, SLASH).unwrap(), "2019/05/01");
+        assert_eq!(format(&date, COMPACT).unwrap(), "20190501");
+        assert_eq!(format(&date, KANJI).unwrap(), "2019年5月1日(水)");
+        assert_eq!(format(&date, ERA).unwrap(), "令和元年5月1日");
+        assert_eq!(format(&date, "%#E%#e年%#m月").unwrap(), "令和元年０５月");
+        assert_eq!(format(&date, "100%%").unwrap(), "100%");
+
+        let date = Date::from_ymd(-44, 3, 15).unwrap();
+        assert_eq!(format(&date, ISO).unwrap(), "-0044-03-15");
+        assert_eq!(format(&date, "%-Y").unwrap(), "-44");
+    }
+
+    #[test]
+    fn format_errors() {
+        let date = Date::from_ymd(1800, 1, 1).unwrap();
+        let err = format(&date, ERA).unwrap_err();
+        assert!(matches!(err, KoyomiError::NoEra(1800, 1, 1)));
+
+        for pattern in ["%", "%Q", "%-"].iter() {
+            let err = format(&date, pattern).unwrap_err();
+            assert!(matches!(err, KoyomiError::InvalidFormat(ref p) if p == pattern));
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let patterns = [
+            ISO,
+            SLASH,
+            COMPACT,
+            KANJI,
+            ERA,
+            "%#Y年%#-m月%#-d日(%a)",
+            "%-d/%-m/%Y",
+            "%E%#e年%m月%d日",
+        ];
+        let mut date = Date::from_ymd(1868, 1, 25).unwrap();
+        while date.year() < 2031 {
+            for pattern in patterns.iter() {
+                let s = format(&date, pattern).unwrap();
+                assert_eq!(parse(&s, pattern).unwrap(), date, "{}", s);
+            }
+            date = date.shift(17).unwrap();
+        }
+
+        for &y in [-262_143, -1, 0, 12_345, 262_142].iter() {
+            // 5桁以上の年も区切りなしの書式で読み戻せる
+            let date = Date::from_ymd(y, 12, 31).unwrap();
+            for pattern in [ISO, COMPACT, KANJI].iter() {
+                let s = format(&date, pattern).unwrap();
+                assert_eq!(parse(&s, pattern).unwrap(), date, "{}", s);
+            }
+        }
+    }
+
+    #[test]
+    fn parse_loosely() {
+        let date = Date::from_ymd(2025, 5, 1).unwrap();
+        assert_eq!(parse("2025-5-1", ISO).unwrap(), date);
+        assert_eq!(parse("２０２５年０５月０１日(木)", KANJI).unwrap(), date);
+        assert_eq!(parse("20250501", "%-Y%-m%-d").unwrap(), date);
+        assert_eq!(parse("123451231", COMPACT).unwrap().year(), 12_345);
+        assert!(parse("R7", "R%-e").is_err());
+    }
+
+    #[test]
+    fn parse_errors() {
+        let invalid = |s: &str, pattern: &str| {
+            matches!(parse(s, pattern), Err(KoyomiError::InvalidFormat(_)))
+        };
+        assert!(invalid("2025年5月1日(金)", KANJI));
+        assert!(invalid("2025年5月1日", KANJI));
+        assert!(invalid("2025-05-01x", ISO));
+        assert!(invalid("2025-05", "%Y-%m"));
+        assert!(invalid("2024年5月1日", "%Y年%-m月%-d日%E"));
+        assert!(invalid("2023/令和6年5月1日", "%Y/%E%e年%-m月%-d日"));
+        assert!(invalid("天平1年1月1日", ERA));
+
+        let err = parse("令和1年4月30日", ERA).unwrap_err();
+        assert!(matches!(err, KoyomiError::OutOfEra(_, 1, 4, 30)));
+        let err = parse("20250230", COMPACT).unwrap_err();
+        assert!(matches!(err, KoyomiError::InvalidDay(2025, 2, 30)));
+    }
+
+    #[test]
+    fn parse_japanese_dates() {
+        let date = Date::from_ymd(2025, 5, 1).unwrap();
+        let inputs = [
+            "2025年5月1日(木)",
+            "2025年5月1日",
+            "令和7年5月1日",
+            "２０２５-０５-０１",
+        ];
+        for s in inputs.iter() {
+            assert_eq!(parse_japanese(s).unwrap(), date, "{}", s);
+        }
+        let err = parse_japanese("2025年2月30日").unwrap_err();
+        assert!(matches!(err, KoyomiError::InvalidDay(2025, 2, 30)));
+        assert!(parse_japanese("五月一日").is_err());
+    }
+}
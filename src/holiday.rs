@@ -3,6 +3,9 @@
 //! 日本の法律で祝日・祭日となる日と、
 //! 指定日が祝祭日にあたるかどうかを判定する
 //! 関数を定義する。
+use alloc::string::String;
+use alloc::vec::Vec;
+
 use crate::KoyomiResult;
 use crate::{Date, Weekday};
 
@@ -27,13 +30,13 @@ pub fn holiday(date: &Date) -> Option<String> {
         // 振替休日(前日が日曜で祝日)
         .or(substitute_holiday(date))
         // 成人の日(1月第2月曜)
-        .or(variable_holiday(1, date, is_second_week))
+        .or(variable_holiday(1, date, 2))
         // 海の日(7月第3月曜)
-        .or(variable_holiday(9, date, is_third_week))
+        .or(variable_holiday(9, date, 3))
         // 敬老の日(9月第3月曜)
-        .or(variable_holiday(11, date, is_third_week))
+        .or(variable_holiday(11, date, 3))
         // 体育の日(10月第2月曜)
-        .or(variable_holiday(12, date, is_second_week))
+        .or(variable_holiday(12, date, 2))
         // 春分の日
         .or(vernal_equinox_day(date))
         // 秋分の日
@@ -44,6 +47,97 @@ pub fn holiday(date: &Date) -> Option<String> {
         .or(spot_holiday(date))
 }
 
+/// 祝祭日の判定元
+///
+/// 国民の祝日のほか、独自に定義した休日なども同じように扱うためのトレイト。
+/// [`or`](#method.or) で複数の判定元を組み合わせることができる。
+///
+/// # Examples
+///
+/// ```rust
+/// use koyomi::{CustomHolidays, Date, HolidaySource, National};
+///
+/// let mut custom = CustomHolidays::new();
+/// custom.add(Date::from_ymd(2018, 12, 28).unwrap(), "仕事納め");
+///
+/// let holidays = National.or(custom);
+/// let date = Date::from_ymd(2018, 1, 1).unwrap();
+/// assert_eq!(holidays.holiday(&date).unwrap(), "元日");
+/// let date = Date::from_ymd(2018, 12, 28).unwrap();
+/// assert_eq!(holidays.holiday(&date).unwrap(), "仕事納め");
+/// ```
+pub trait HolidaySource {
+    /// 指定日が休日であればその名前を返す
+    fn holiday(&self, date: &Date) -> Option<String>;
+
+    /// 自分で判定できなかった日を、もう一方の判定元で判定する
+    fn or<S: HolidaySource>(self, other: S) -> Chain<Self, S>
+    where
+        Self: Sized,
+    {
+        Chain {
+            first: self,
+            second: other,
+        }
+    }
+}
+
+/// 国民の祝日に関する法律に基づく祝祭日
+///
+/// [`holiday`](fn.holiday.html) を [`HolidaySource`](trait.HolidaySource.html) として扱う。
+#[derive(Clone, Copy, Debug, Default)]
+pub struct National;
+
+impl HolidaySource for National {
+    fn holiday(&self, date: &Date) -> Option<String> {
+        holiday(date)
+    }
+}
+
+/// 2つの判定元を組み合わせた判定元
+///
+/// 先に指定した判定元を優先する。
+#[derive(Clone, Debug)]
+pub struct Chain<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A: HolidaySource, B: HolidaySource> HolidaySource for Chain<A, B> {
+    fn holiday(&self, date: &Date) -> Option<String> {
+        self.first
+            .holiday(date)
+            .or_else(|| self.second.holiday(date))
+    }
+}
+
+/// 指定年の祝祭日を日付順にすべて返す
+///
+/// # Examples
+///
+/// ```rust
+/// use koyomi;
+///
+/// let holidays = koyomi::holidays(2018).unwrap();
+/// assert_eq!(holidays.len(), 20);
+/// assert_eq!(holidays[0].0.to_string(), "2018-01-01");
+/// assert_eq!(holidays[0].1, "元日");
+/// ```
+pub fn holidays(year: i32) -> KoyomiResult<Vec<(Date, String)>> {
+    let mut date = Some(Date::from_ymd(year, 1, 1)?);
+    let mut holidays = Vec::new();
+
+    // 扱える最後の年は、翌日がないところで終える
+    while let Some(d) = date.filter(|d| d.year() == year) {
+        if let Some(name) = holiday(&d) {
+            holidays.push((d, name));
+        }
+        date = d.tomorrow().ok();
+    }
+
+    Ok(holidays)
+}
+
 /// 秋分日
 /// @see https://ja.wikipedia.org/wiki/秋分の日
 const AUTUMNAL_EQUINOX_DAYS: [[u32; 4]; 7] = [
@@ -103,9 +197,6 @@ const HOLIDAY_FROM: i32 = 1948;
 /// 国民の休日に関する法律が施行された年
 const NATION_FROM: i32 = 1986;
 
-/// 1週間は何日か？(指定日が何週目にあたるかの判定で利用する)
-const ONE_WEEK: u32 = 7;
-
 /// 振替休日に関する法律が施行された年
 const SUBSTITUTE_FROM: i32 = 1973;
 
@@ -156,50 +247,31 @@ fn defined_holiday(date: &Date) -> Option<String> {
             Some(until) => h.1 <= date.year() && date.year() <= until,
             None => h.1 <= date.year(),
         })
-        .filter(|h| date.month() == h.2 && date.day() == h.3)
-        .nth(0)
+        .find(|h| date.month() == h.2 && date.day() == h.3)
         .map(|h| h.0.into())
 }
 
-/// 指定日が第2週にあたるかどうかを判定する
-/// 第2週の月曜日となった、成人の日・体育の日を判定するために利用する
-fn is_second_week(day: u32) -> bool {
-    (day / ONE_WEEK == 2 && day % ONE_WEEK == 0) || (day / ONE_WEEK == 1 && day % ONE_WEEK > 0)
-}
-
-/// 指定日が第3週にあたるかどうかを判定する
-/// 第3週の月曜日となった、海の日・敬老の日を判定するために利用する
-fn is_third_week(day: u32) -> bool {
-    (day / ONE_WEEK == 3 && day % ONE_WEEK == 0) || (day / ONE_WEEK == 2 && day % ONE_WEEK >= 1)
-}
-
 /// 指定日が国民の休日にあたるかどうかを判定する
 fn national_holiday(date: &Date) -> Option<String> {
     if date.year() < NATION_FROM {
         return None;
     }
 
-    if date.weekday() == &Weekday::Sunday {
+    if date.weekday() == Weekday::Sunday {
         return None;
     }
 
-    if defined_holiday(&date).is_some() {
+    if defined_holiday(date).is_some() {
         return None;
     }
 
     // シルバーウィークで、敬老の日(可変)を考慮する必要がある
     let yesterday = date.yesterday().ok()?;
-    let between = defined_holiday(&yesterday).or(variable_holiday(11, &yesterday, is_third_week));
-    if between.is_none() {
-        return None;
-    }
+    defined_holiday(&yesterday).or(variable_holiday(11, &yesterday, 3))?;
 
     // シルバーウィークで、秋分の日を考慮する必要がある
     let tomorrow = date.tomorrow().ok()?;
-    let between = defined_holiday(&tomorrow).or(autumnal_equinox_day(&tomorrow));
-    if between.is_none() {
-        return None;
-    }
+    defined_holiday(&tomorrow).or(autumnal_equinox_day(&tomorrow))?;
 
     Some("国民の休日".into())
 }
@@ -208,8 +280,7 @@ fn national_holiday(date: &Date) -> Option<String> {
 /// 祝日には、特定の年度だけ制定されるものもある
 fn spot_holiday(date: &Date) -> Option<String> {
     SPOT.iter()
-        .filter(|t| t.1 == date.year() && t.2 == date.month() && t.3 == date.day())
-        .nth(0)
+        .find(|t| t.1 == date.year() && t.2 == date.month() && t.3 == date.day())
         .map(|h| h.0.into())
 }
 
@@ -226,7 +297,7 @@ fn substitute(yesterday: KoyomiResult<Date>) -> Option<String> {
                 .or(autumnal_equinox_day(&y));
             match holiday {
                 None => None,
-                Some(_) if y.weekday() == &Weekday::Sunday => Some("振替休日".into()),
+                Some(_) if y.weekday() == Weekday::Sunday => Some("振替休日".into()),
                 Some(_) => substitute(y.yesterday()),
             }
         }
@@ -242,13 +313,14 @@ fn substitute_holiday(date: &Date) -> Option<String> {
     }
 }
 
-/// 指定日が年ごとに変動する祝日かどうかを判定する
-fn variable_holiday(index: usize, date: &Date, week: impl Fn(u32) -> bool) -> Option<String> {
+/// 指定日が年ごとに変動する祝日(第n月曜日)かどうかを判定する
+/// 成人の日・体育の日は第2月曜日、海の日・敬老の日は第3月曜日となる
+fn variable_holiday(index: usize, date: &Date, nth: u32) -> Option<String> {
     if date.month() != HOLIDAYS[index].2 {
         return None;
     }
 
-    if date.weekday() != &Weekday::Monday {
+    if date.weekday() != Weekday::Monday {
         return None;
     }
 
@@ -256,7 +328,7 @@ fn variable_holiday(index: usize, date: &Date, week: impl Fn(u32) -> bool) -> Op
         return None;
     }
 
-    if !week(date.day()) {
+    if date.weekday_ordinal() != nth {
         return None;
     }
 
@@ -633,6 +705,24 @@ mod tests {
         assert_ne!(holiday(&date).unwrap(), name);
     }
 
+    #[test]
+    fn holidays_of_year() {
+        let days = holidays(2019).unwrap();
+        assert_eq!(days.len(), 22);
+        assert_eq!(days[0].0.to_string(), "2019-01-01");
+        assert_eq!(days[20].0.to_string(), "2019-11-04");
+        assert_eq!(days[20].1, "振替休日");
+        assert_eq!(days[21].0.to_string(), "2019-11-23");
+    }
+
+    #[test]
+    fn holidays_of_edge_years() {
+        let days = holidays(262_142).unwrap();
+        assert_eq!(days[0].0.to_string(), "262142-01-01");
+        assert!(holidays(-262_143).is_ok());
+        assert!(holidays(262_143).is_err());
+    }
+
     #[test]
     fn special_holiday() {
         let name = "新天皇即位日";
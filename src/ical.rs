@@ -0,0 +1,630 @@
+//! # iCalendar
+//!
+//! 祝祭日を iCalendar(RFC 5545)形式で書き出す。
+//! 祝祭日は終日の VEVENT となり、Google カレンダーや Outlook に取り込むことができる。
+//!
+//! 逆に、iCalendar 形式の終日の VEVENT を読み込むこともできる。
+use std::io::{self, Write};
+
+use chrono::{NaiveDateTime, Utc};
+
+use crate::{Calendar, Date, KoyomiError, KoyomiResult, Locale, Weekday};
+
+/// 改行コード(RFC 5545 では CRLF)
+const CRLF: &str = "\r\n";
+
+/// 1行の最大オクテット数
+const LINE_OCTETS: usize = 75;
+
+/// iCalendar 形式の書き出し
+///
+/// # Examples
+///
+/// ```rust
+/// use koyomi::{Calendar, IcsExporter};
+///
+/// let cal = Calendar::build().single("2018-01").finalize().unwrap();
+/// let mut ics = Vec::new();
+/// IcsExporter::new().write_calendar(&cal, &mut ics).unwrap();
+///
+/// let ics = String::from_utf8(ics).unwrap();
+/// assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+/// assert!(ics.contains("SUMMARY:元日\r\n"));
+/// assert!(ics.contains("SUMMARY:成人の日\r\n"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct IcsExporter {
+    english: bool,
+    name: Option<String>,
+    timestamp: Option<NaiveDateTime>,
+}
+
+impl IcsExporter {
+    /// 既定の書き出し形式を生成する
+    pub fn new() -> Self {
+        IcsExporter {
+            english: false,
+            name: None,
+            timestamp: None,
+        }
+    }
+
+    /// 英語名を DESCRIPTION として書き出すかどうかを指定する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{holidays, IcsExporter};
+    ///
+    /// let mut ics = Vec::new();
+    /// IcsExporter::new()
+    ///     .english(true)
+    ///     .write_holidays(&holidays(2018).unwrap(), &mut ics)
+    ///     .unwrap();
+    ///
+    /// let ics = String::from_utf8(ics).unwrap();
+    /// assert!(ics.contains("DESCRIPTION:New Year's Day\r\n"));
+    /// ```
+    pub fn english(mut self, english: bool) -> Self {
+        self.english = english;
+        self
+    }
+
+    /// カレンダー名(X-WR-CALNAME)を指定する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{holidays, IcsExporter};
+    ///
+    /// let mut ics = Vec::new();
+    /// IcsExporter::new()
+    ///     .name("日本の祝日")
+    ///     .write_holidays(&holidays(2018).unwrap(), &mut ics)
+    ///     .unwrap();
+    ///
+    /// let ics = String::from_utf8(ics).unwrap();
+    /// assert!(ics.contains("X-WR-CALNAME:日本の祝日\r\n"));
+    /// ```
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// 作成日時(DTSTAMP)を UTC で指定する
+    /// 指定しない場合は書き出した日時となる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use chrono::NaiveDate;
+    /// use koyomi::{holidays, IcsExporter};
+    ///
+    /// let stamp = NaiveDate::from_ymd_opt(2018, 1, 1)
+    ///     .unwrap()
+    ///     .and_hms_opt(0, 0, 0)
+    ///     .unwrap();
+    /// let mut ics = Vec::new();
+    /// IcsExporter::new()
+    ///     .timestamp(stamp)
+    ///     .write_holidays(&holidays(2018).unwrap(), &mut ics)
+    ///     .unwrap();
+    ///
+    /// let ics = String::from_utf8(ics).unwrap();
+    /// assert!(ics.contains("DTSTAMP:20180101T000000Z\r\n"));
+    /// ```
+    pub fn timestamp(mut self, timestamp: NaiveDateTime) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// カレンダーの期間に含まれる祝祭日を書き出す
+    pub fn write_calendar<W: Write>(&self, calendar: &Calendar, w: &mut W) -> io::Result<()> {
+        let holidays = calendar
+            .iter()
+            .filter_map(|d| d.holiday().map(|h| (d, h)))
+            .collect::<Vec<_>>();
+        self.write_holidays(&holidays, w)
+    }
+
+    /// 日付と祝祭日名の組を書き出す
+    /// [`holidays`](fn.holidays.html) の結果をそのまま渡すことができる
+    ///
+    /// iCalendar の日付は4桁の年で表すため、0〜9999年以外の日付を含む場合は
+    /// 何も書き出さずにエラーとなる
+    pub fn write_holidays<W: Write>(
+        &self,
+        holidays: &[(Date, String)],
+        w: &mut W,
+    ) -> io::Result<()> {
+        // 書き出す前に、すべての日付を iCalendar の表現にしておく
+        let days = holidays
+            .iter()
+            .map(|(date, name)| {
+                let end = date.tomorrow().map_err(invalid_input)?;
+                Ok((compact(date)?, compact(&end)?, name))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        let stamp = self
+            .timestamp
+            .unwrap_or_else(|| Utc::now().naive_utc())
+            .format("%Y%m%dT%H%M%SZ")
+            .to_string();
+
+        write_line(w, "BEGIN:VCALENDAR")?;
+        write_line(w, "VERSION:2.0")?;
+        write_line(
+            w,
+            &format!("PRODID:-//koyomi//koyomi {}//JA", env!("CARGO_PKG_VERSION")),
+        )?;
+        write_line(w, "CALSCALE:GREGORIAN")?;
+        if let Some(ref name) = self.name {
+            write_line(w, &format!("X-WR-CALNAME:{}", escape(name)))?;
+        }
+
+        for (start, end, name) in days {
+            write_line(w, "BEGIN:VEVENT")?;
+            write_line(w, &format!("UID:{}-holiday@koyomi", start))?;
+            write_line(w, &format!("DTSTAMP:{}", stamp))?;
+            write_line(w, &format!("DTSTART;VALUE=DATE:{}", start))?;
+            write_line(w, &format!("DTEND;VALUE=DATE:{}", end))?;
+            write_line(w, &format!("SUMMARY:{}", escape(name)))?;
+            if self.english {
+                if let Some(english) = Locale::English.holiday(name) {
+                    write_line(w, &format!("DESCRIPTION:{}", escape(english)))?;
+                }
+            }
+            write_line(w, "TRANSP:TRANSPARENT")?;
+            write_line(w, "END:VEVENT")?;
+        }
+
+        write_line(w, "END:VCALENDAR")
+    }
+}
+
+impl Default for IcsExporter {
+    fn default() -> Self {
+        IcsExporter::new()
+    }
+}
+
+/// 日付の `YYYYMMDD` 表現を返す
+/// 4桁で表せない年の日付はエラーとなる
+fn compact(date: &Date) -> io::Result<String> {
+    let (y, m, d) = (date.year(), date.month(), date.day());
+    if !(0..=9999).contains(&y) {
+        return Err(invalid_input(KoyomiError::OutOfRange(y, m, d)));
+    }
+    Ok(format!("{:04}{:02}{:02}", y, m, d))
+}
+
+/// 書き出せない日付を `io::Error` として返す
+fn invalid_input(err: KoyomiError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, err)
+}
+
+/// TEXT 型の値をエスケープする
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// 1行を書き出す
+/// 75オクテットを超える行は、マルチバイト文字の途中で切らないように折り返す
+fn write_line<W: Write>(w: &mut W, line: &str) -> io::Result<()> {
+    let mut octets = 0;
+    for c in line.chars() {
+        if octets + c.len_utf8() > LINE_OCTETS {
+            w.write_all(CRLF.as_bytes())?;
+            w.write_all(b" ")?;
+            // 折り返し行は先頭の空白も1オクテットとして数える
+            octets = 1;
+        }
+        let mut buf = [0; 4];
+        w.write_all(c.encode_utf8(&mut buf).as_bytes())?;
+        octets += c.len_utf8();
+    }
+    w.write_all(CRLF.as_bytes())
+}
+
+/// VEVENT のプロパティ(名前・パラメータ・値)
+type Property = (String, String, String);
+
+/// 読み込んだ終日の VEVENT
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Event {
+    /// 予定の名前(SUMMARY)
+    pub summary: String,
+    /// 初日(DTSTART)
+    pub start: Date,
+    /// 日数(DTEND がなければ1日)
+    pub days: i64,
+    /// 毎年の繰り返し(RRULE)
+    pub rule: Option<Rule>,
+    /// 除外する回の初日(EXDATE)
+    pub exdates: Vec<Date>,
+}
+
+/// 毎年の繰り返し規則(`FREQ=YEARLY`)
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rule {
+    /// 何年ごとに繰り返すか(INTERVAL)
+    pub interval: u32,
+    /// 繰り返しの回数(COUNT)
+    pub count: Option<u32>,
+    /// 繰り返しの最終日(UNTIL)
+    pub until: Option<Date>,
+    /// 対象の月(BYMONTH)
+    pub months: Vec<u32>,
+    /// 対象の日(BYMONTHDAY)、負数は月末から数える
+    pub month_days: Vec<i32>,
+    /// 対象の曜日(BYDAY)と、第n(負数は最後から数える)の指定
+    pub weekdays: Vec<(Option<i32>, Weekday)>,
+}
+
+impl Rule {
+    /// DTSTART と同じ月日で毎年繰り返す規則を返す
+    pub fn yearly() -> Self {
+        Rule {
+            interval: 1,
+            count: None,
+            until: None,
+            months: Vec::new(),
+            month_days: Vec::new(),
+            weekdays: Vec::new(),
+        }
+    }
+}
+
+/// iCalendar 形式の文字列から終日の VEVENT を読み込む
+/// 時刻を持つ予定や `FREQ=YEARLY` 以外の繰り返しを持つ予定は読み飛ばす
+///
+/// `FREQ=YEARLY` の BYxxx は BYMONTH・BYMONTHDAY・BYDAY のみに対応し、
+/// それ以外を持つ予定はエラーとなる
+pub fn parse_events(text: &str) -> KoyomiResult<Vec<Event>> {
+    let mut events = Vec::new();
+    let mut props: Option<Vec<Property>> = None;
+
+    for line in unfold(text) {
+        let (name, value) = match line.split_once(':') {
+            Some(p) => p,
+            None if line.is_empty() => continue,
+            None => return Err(KoyomiError::InvalidFormat(line)),
+        };
+        let mut params = name.split(';');
+        let name = params.next().unwrap_or("").to_ascii_uppercase();
+
+        match (name.as_str(), value.to_ascii_uppercase().as_str()) {
+            ("BEGIN", "VEVENT") => props = Some(Vec::new()),
+            ("END", "VEVENT") => {
+                if let Some(event) = props.take().map(|p| event(&p)).transpose()? {
+                    events.extend(event);
+                }
+            }
+            _ => {
+                if let Some(p) = props.as_mut() {
+                    let params = params.collect::<Vec<_>>().join(";");
+                    p.push((name, params, value.to_owned()));
+                }
+            }
+        }
+    }
+
+    Ok(events)
+}
+
+/// VEVENT のプロパティから予定を組み立てる
+/// 終日の予定でなければ `None` となる
+fn event(props: &[Property]) -> KoyomiResult<Option<Event>> {
+    let find = |name: &str| {
+        props
+            .iter()
+            .find(|p| p.0 == name)
+            .map(|p| (p.1.as_str(), p.2.as_str()))
+    };
+
+    let (params, value) = match find("DTSTART") {
+        Some(p) => p,
+        None => return Ok(None),
+    };
+    if !is_all_day(params, value) {
+        return Ok(None);
+    }
+    let start = parse_compact(value)?;
+
+    let days = match find("DTEND") {
+        Some((_, value)) => parse_compact(value)?.num_days(&start).max(1),
+        None => 1,
+    };
+
+    let rule = match find("RRULE") {
+        Some((_, value)) => match parse_rule(value)? {
+            Some(rule) => Some(rule),
+            None => return Ok(None),
+        },
+        None => None,
+    };
+
+    let mut exdates = Vec::new();
+    for p in props.iter().filter(|p| p.0 == "EXDATE") {
+        for value in p.2.split(',') {
+            exdates.push(parse_compact(value)?);
+        }
+    }
+
+    let summary = find("SUMMARY").map_or(String::new(), |(_, v)| unescape(v));
+    Ok(Some(Event {
+        summary,
+        start,
+        days,
+        rule,
+        exdates,
+    }))
+}
+
+/// 終日の予定かどうかを判定する
+fn is_all_day(params: &str, value: &str) -> bool {
+    params.to_ascii_uppercase().contains("VALUE=DATE") && !value.contains('T')
+        || value.len() == 8 && value.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// RRULE を解析する
+/// `FREQ=YEARLY` 以外は `None` となる
+fn parse_rule(value: &str) -> KoyomiResult<Option<Rule>> {
+    let invalid = || KoyomiError::InvalidFormat(value.into());
+    let mut yearly = false;
+    let mut unsupported = false;
+    let mut rule = Rule::yearly();
+
+    for part in value.split(';') {
+        let (key, val) = part.split_once('=').ok_or_else(invalid)?;
+        match key.to_ascii_uppercase().as_str() {
+            "FREQ" => yearly = val.eq_ignore_ascii_case("YEARLY"),
+            "INTERVAL" => {
+                rule.interval = val
+                    .parse()
+                    .map_err(|e| KoyomiError::InvalidNumber(val.into(), e))?
+            }
+            "COUNT" => {
+                rule.count = Some(
+                    val.parse()
+                        .map_err(|e| KoyomiError::InvalidNumber(val.into(), e))?,
+                )
+            }
+            "UNTIL" => rule.until = Some(parse_compact(val.get(..8).ok_or_else(invalid)?)?),
+            "BYMONTH" => {
+                for month in val.split(',') {
+                    match parse_number(month)? {
+                        m @ 1..=12 => rule.months.push(m as u32),
+                        _ => return Err(invalid()),
+                    }
+                }
+            }
+            "BYMONTHDAY" => {
+                for day in val.split(',') {
+                    match parse_number(day)? {
+                        d @ -31..=-1 | d @ 1..=31 => rule.month_days.push(d),
+                        _ => return Err(invalid()),
+                    }
+                }
+            }
+            "BYDAY" => {
+                for day in val.split(',') {
+                    rule.weekdays.push(parse_weekday(day).ok_or_else(invalid)?);
+                }
+            }
+            key if key.starts_with("BY") => unsupported = true,
+            _ => {}
+        }
+    }
+
+    if rule.interval == 0 {
+        return Err(invalid());
+    }
+    match (yearly, unsupported) {
+        (true, true) => Err(invalid()),
+        (true, false) => Ok(Some(rule)),
+        (false, _) => Ok(None),
+    }
+}
+
+/// BYxxx の数値を解析する
+fn parse_number(value: &str) -> KoyomiResult<i32> {
+    value
+        .parse()
+        .map_err(|e| KoyomiError::InvalidNumber(value.into(), e))
+}
+
+/// BYDAY の1要素(`MO`・`2MO`・`-1FR` など)を解析する
+fn parse_weekday(value: &str) -> Option<(Option<i32>, Weekday)> {
+    let split = value.len().checked_sub(2)?;
+    let weekday = match value.get(split..)?.to_ascii_uppercase().as_str() {
+        "MO" => Weekday::Monday,
+        "TU" => Weekday::Tuesday,
+        "WE" => Weekday::Wednesday,
+        "TH" => Weekday::Thursday,
+        "FR" => Weekday::Friday,
+        "SA" => Weekday::Saturday,
+        "SU" => Weekday::Sunday,
+        _ => return None,
+    };
+    let nth = match &value[..split] {
+        "" => None,
+        n => match n.parse().ok()? {
+            n @ -53..=-1 | n @ 1..=53 => Some(n),
+            _ => return None,
+        },
+    };
+    Some((nth, weekday))
+}
+
+/// `YYYYMMDD` 表現の日付を解析する
+fn parse_compact(value: &str) -> KoyomiResult<Date> {
+    let invalid = || KoyomiError::InvalidFormat(value.into());
+    let digits = value.get(..8).ok_or_else(invalid)?;
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+
+    let year = digits[..4].parse().map_err(|_| invalid())?;
+    let month = digits[4..6].parse().map_err(|_| invalid())?;
+    let day = digits[6..].parse().map_err(|_| invalid())?;
+    Date::from_ymd(year, month, day)
+}
+
+/// 折り返された行を元に戻す
+fn unfold(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.lines() {
+        let line = line.trim_end_matches('\r');
+        match (
+            line.strip_prefix(' ').or_else(|| line.strip_prefix('\t')),
+            lines.last_mut(),
+        ) {
+            (Some(rest), Some(last)) => last.push_str(rest),
+            _ => lines.push(line.to_owned()),
+        }
+    }
+    lines
+}
+
+/// TEXT 型の値のエスケープを元に戻す
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('n') | Some('N') => unescaped.push('\n'),
+                Some(c) => unescaped.push(c),
+                None => unescaped.push('\\'),
+            },
+            c => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn export(exporter: IcsExporter, calendar: &Calendar) -> String {
+        let stamp = NaiveDate::from_ymd_opt(2018, 1, 1)
+            .unwrap()
+            .and_hms_opt(12, 34, 56)
+            .unwrap();
+        let mut ics = Vec::new();
+        exporter
+            .timestamp(stamp)
+            .write_calendar(calendar, &mut ics)
+            .unwrap();
+        String::from_utf8(ics).unwrap()
+    }
+
+    #[test]
+    fn export_calendar() {
+        let cal = Calendar::build().single("2018-05").finalize().unwrap();
+        let ics = export(IcsExporter::new(), &cal);
+        let expected = "BEGIN:VCALENDAR\r
+VERSION:2.0\r
+PRODID:-//koyomi//koyomi "
+            .to_owned()
+            + env!("CARGO_PKG_VERSION")
+            + "//JA\r
+CALSCALE:GREGORIAN\r
+BEGIN:VEVENT\r
+UID:20180503-holiday@koyomi\r
+DTSTAMP:20180101T123456Z\r
+DTSTART;VALUE=DATE:20180503\r
+DTEND;VALUE=DATE:20180504\r
+SUMMARY:憲法記念日\r
+TRANSP:TRANSPARENT\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:20180504-holiday@koyomi\r
+DTSTAMP:20180101T123456Z\r
+DTSTART;VALUE=DATE:20180504\r
+DTEND;VALUE=DATE:20180505\r
+SUMMARY:みどりの日\r
+TRANSP:TRANSPARENT\r
+END:VEVENT\r
+BEGIN:VEVENT\r
+UID:20180505-holiday@koyomi\r
+DTSTAMP:20180101T123456Z\r
+DTSTART;VALUE=DATE:20180505\r
+DTEND;VALUE=DATE:20180506\r
+SUMMARY:こどもの日\r
+TRANSP:TRANSPARENT\r
+END:VEVENT\r
+END:VCALENDAR\r
+";
+        assert_eq!(ics, expected);
+    }
+
+    #[test]
+    fn export_english_description() {
+        let cal = Calendar::build().single("2019").finalize().unwrap();
+        let ics = export(IcsExporter::new().english(true), &cal);
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 22);
+        assert_eq!(ics.matches("DESCRIPTION:").count(), 22);
+        assert!(ics.contains("DESCRIPTION:Enthronement Day\r\n"));
+        assert!(ics.contains("DESCRIPTION:Respect for the Aged Day\r\n"));
+    }
+
+    #[test]
+    fn stable_uid() {
+        let cal = Calendar::build().single("2018").finalize().unwrap();
+        let first = export(IcsExporter::new(), &cal);
+        let second = export(IcsExporter::new(), &cal);
+        assert_eq!(first, second);
+        assert!(first.contains("UID:20180101-holiday@koyomi\r\n"));
+    }
+
+    #[test]
+    fn reject_years_beyond_four_digits() {
+        for &year in &[10000, -1] {
+            let holidays = vec![(Date::from_ymd(year, 1, 1).unwrap(), "元日".to_owned())];
+            let mut ics = Vec::new();
+            let err = IcsExporter::new()
+                .write_holidays(&holidays, &mut ics)
+                .unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+            assert!(ics.is_empty());
+        }
+
+        // 9999年の祝日は書き出せる
+        let cal = Calendar::build().single("9999-01").finalize().unwrap();
+        let ics = export(IcsExporter::new(), &cal);
+        assert!(ics.contains("DTSTART;VALUE=DATE:99990101\r\n"));
+    }
+
+    #[test]
+    fn escape_text() {
+        assert_eq!(escape("a,b;c\\d\ne"), "a\\,b\\;c\\\\d\\ne");
+    }
+
+    #[test]
+    fn fold_long_line() {
+        let mut out = Vec::new();
+        let line = format!("SUMMARY:{}", "祝".repeat(30));
+        write_line(&mut out, &line).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        let lines = out.split("\r\n").collect::<Vec<_>>();
+        assert_eq!(lines.len(), 3);
+        assert!(lines.iter().all(|l| l.len() <= LINE_OCTETS));
+        assert!(lines[1].starts_with(' '));
+        assert_eq!(out.replace("\r\n ", ""), format!("{}\r\n", line));
+    }
+}
@@ -1,36 +1,70 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+mod cabinet;
 mod calendar;
+#[cfg(all(feature = "std", feature = "chrono"))]
+mod custom;
 mod date;
 mod era;
+mod error;
+#[cfg(feature = "chrono")]
+mod ext;
+mod fiscal;
+pub mod format;
 mod holiday;
+#[cfg(all(feature = "std", feature = "chrono"))]
+mod ical;
+mod locale;
+mod month;
+mod render;
+mod school;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "std")]
+mod table;
+mod week;
+
+#[cfg(feature = "std")]
+pub use cabinet::{CabinetHolidays, Mismatch};
 
 pub use calendar::Calendar;
 pub use calendar::CalendarBuilder;
+pub use calendar::{Days, MonthSteps};
 pub use calendar::is_leap;
 pub use calendar::num_days;
 
+#[cfg(all(feature = "std", feature = "chrono"))]
+pub use custom::CustomHolidays;
+
 pub use date::Date;
 pub use date::Weekday;
 
 pub use era::{era, Era};
 
-pub use holiday::holiday;
-
-/// クレート単位の`Result`
-///
-/// 失敗時は `KoyomiError` を返す
-pub type KoyomiResult<T> = Result<T, KoyomiError>;
-
-/// クレート単位のエラーバリアント
-#[derive(Debug)]
-pub enum KoyomiError {
-    /// 妥当な日付ではない
-    InvalidFormat(String),
-    /// カレンダーの期間指定が妥当ではない
-    InvalidTerm(Date, Date),
-    /// カレンダーを生成するための指定が不足している
-    NotEnough,
-    /// 指定日の翌日は存在しない
-    NoTomorrow(i32, u32, u32),
-    /// 指定日の前日は存在しない
-    NoYesterday(i32, u32, u32),
-}
+pub use error::{KoyomiError, KoyomiResult};
+
+#[cfg(feature = "chrono")]
+pub use ext::JapaneseCalendarExt;
+
+pub use fiscal::{FiscalYear, Half};
+
+pub use holiday::{holiday, holidays, Chain, HolidaySource, National};
+
+#[cfg(all(feature = "std", feature = "chrono"))]
+pub use ical::IcsExporter;
+
+pub use locale::Locale;
+
+pub use month::{Cell, MonthGrid};
+
+pub use render::Renderer;
+
+pub use school::{cohort, Cohort, Grade, School};
+
+pub use week::IsoWeek;
+
+#[cfg(feature = "std")]
+pub use table::HolidayTable;
@@ -0,0 +1,246 @@
+//! # 言語
+//!
+//! 祝祭日・曜日・元号の名前を、日本語以外でも表すための定義。
+//! 名前の対応表はすべてこのモジュールで持ち、
+//! 各型の `*_in` メソッドは [`Locale`](enum.Locale.html) を受け取ってここを引く。
+use core::str::FromStr;
+
+use crate::{KoyomiError, Weekday};
+
+/// 祝祭日の名前(日本語・英語・読み)
+const HOLIDAYS: [(&str, &str, &str); 20] = [
+    ("元日", "New Year's Day", "がんじつ"),
+    ("成人の日", "Coming of Age Day", "せいじんのひ"),
+    ("建国記念日", "National Foundation Day", "けんこくきねんび"),
+    (
+        "天皇誕生日",
+        "The Emperor's Birthday",
+        "てんのうたんじょうび",
+    ),
+    ("みどりの日", "Greenery Day", "みどりのひ"),
+    ("昭和の日", "Showa Day", "しょうわのひ"),
+    (
+        "憲法記念日",
+        "Constitution Memorial Day",
+        "けんぽうきねんび",
+    ),
+    ("こどもの日", "Children's Day", "こどものひ"),
+    ("海の日", "Marine Day", "うみのひ"),
+    ("山の日", "Mountain Day", "やまのひ"),
+    ("敬老の日", "Respect for the Aged Day", "けいろうのひ"),
+    ("体育の日", "Health and Sports Day", "たいいくのひ"),
+    ("文化の日", "Culture Day", "ぶんかのひ"),
+    (
+        "勤労感謝の日",
+        "Labor Thanksgiving Day",
+        "きんろうかんしゃのひ",
+    ),
+    ("振替休日", "Substitute Holiday", "ふりかえきゅうじつ"),
+    ("春分の日", "Vernal Equinox Day", "しゅんぶんのひ"),
+    ("秋分の日", "Autumnal Equinox Day", "しゅうぶんのひ"),
+    ("国民の休日", "Citizens' Holiday", "こくみんのきゅうじつ"),
+    ("新天皇即位日", "Enthronement Day", "しんてんのうそくいび"),
+    (
+        "即位礼正殿の儀",
+        "Enthronement Ceremony",
+        "そくいれいせいでんのぎ",
+    ),
+];
+
+/// 元号の名前(日本語・英語・読み)
+const ERAS: [(&str, &str, &str); 5] = [
+    ("令和", "Reiwa", "れいわ"),
+    ("平成", "Heisei", "へいせい"),
+    ("昭和", "Showa", "しょうわ"),
+    ("大正", "Taisho", "たいしょう"),
+    ("明治", "Meiji", "めいじ"),
+];
+
+/// 曜日の短い名前(日本語・英語・読み)
+/// 月曜から順に並ぶ
+const WEEKDAYS: [(&str, &str, &str); 7] = [
+    ("月", "Mon", "げつ"),
+    ("火", "Tue", "か"),
+    ("水", "Wed", "すい"),
+    ("木", "Thu", "もく"),
+    ("金", "Fri", "きん"),
+    ("土", "Sat", "ど"),
+    ("日", "Sun", "にち"),
+];
+
+/// 曜日の名前(日本語・英語・読み)
+/// 月曜から順に並ぶ
+const LONG_WEEKDAYS: [(&str, &str, &str); 7] = [
+    ("月曜日", "Monday", "げつようび"),
+    ("火曜日", "Tuesday", "かようび"),
+    ("水曜日", "Wednesday", "すいようび"),
+    ("木曜日", "Thursday", "もくようび"),
+    ("金曜日", "Friday", "きんようび"),
+    ("土曜日", "Saturday", "どようび"),
+    ("日曜日", "Sunday", "にちようび"),
+];
+
+/// 名前を表す言語
+///
+/// 文字列(`"ja"`・`"en"`・`"kana"`)から生成することもできる。
+///
+/// # Examples
+///
+/// ```rust
+/// use koyomi::{Date, Locale};
+///
+/// let date = Date::from_ymd(2020, 1, 13).unwrap();
+/// assert_eq!(date.holiday_in(Locale::English).unwrap(), "Coming of Age Day");
+/// assert_eq!(date.weekday().name_in(Locale::English), "Mon");
+/// assert_eq!(date.era().unwrap().format_in(Locale::English), "Reiwa 2");
+///
+/// let locale: Locale = "kana".parse().unwrap();
+/// assert_eq!(date.holiday_in(locale).unwrap(), "せいじんのひ");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Locale {
+    /// 日本語
+    #[default]
+    Japanese,
+    /// 英語
+    English,
+    /// ひらがなの読み
+    Kana,
+}
+
+impl Locale {
+    /// 言語を表す文字列(`"ja"`・`"en"`・`"kana"`)を返す
+    pub fn tag(&self) -> &'static str {
+        match *self {
+            Locale::Japanese => "ja",
+            Locale::English => "en",
+            Locale::Kana => "kana",
+        }
+    }
+
+    /// 日本語の祝祭日名を、この言語の名前に変換する
+    /// 国民の祝日以外の名前は `None` となる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Locale;
+    ///
+    /// assert_eq!(Locale::English.holiday("元日"), Some("New Year's Day"));
+    /// assert_eq!(Locale::Kana.holiday("山の日"), Some("やまのひ"));
+    /// assert_eq!(Locale::English.holiday("仕事納め"), None);
+    /// ```
+    pub fn holiday(&self, name: &str) -> Option<&'static str> {
+        lookup(&HOLIDAYS, name).map(|names| self.pick(names))
+    }
+
+    /// 日本語の元号名を、この言語の名前に変換する
+    /// 明治以降の元号以外は `None` となる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::Locale;
+    ///
+    /// assert_eq!(Locale::English.era("令和"), Some("Reiwa"));
+    /// assert_eq!(Locale::Kana.era("平成"), Some("へいせい"));
+    /// ```
+    pub fn era(&self, name: &str) -> Option<&'static str> {
+        lookup(&ERAS, name).map(|names| self.pick(names))
+    }
+
+    /// 曜日の短い名前(「月」・"Mon"・「げつ」)を返す
+    pub fn weekday(&self, weekday: Weekday) -> &'static str {
+        self.pick(&WEEKDAYS[weekday.num_days_from_monday() as usize])
+    }
+
+    /// 曜日の名前(「月曜日」・"Monday"・「げつようび」)を返す
+    pub fn long_weekday(&self, weekday: Weekday) -> &'static str {
+        self.pick(&LONG_WEEKDAYS[weekday.num_days_from_monday() as usize])
+    }
+
+    /// 対応表の組から、この言語の名前を選ぶ
+    fn pick(&self, names: &(&'static str, &'static str, &'static str)) -> &'static str {
+        match *self {
+            Locale::Japanese => names.0,
+            Locale::English => names.1,
+            Locale::Kana => names.2,
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = KoyomiError;
+
+    /// `"ja"`・`"en"`・`"kana"` から生成する
+    /// `"ja-JP"` のような地域付きの表記も受け付ける
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lang = s.split(['-', '_']).next().unwrap_or(s);
+        match lang.to_ascii_lowercase().as_str() {
+            "ja" => Ok(Locale::Japanese),
+            "en" => Ok(Locale::English),
+            "kana" => Ok(Locale::Kana),
+            _ => Err(KoyomiError::UnknownLocale(s.into())),
+        }
+    }
+}
+
+/// 日本語の名前で対応表を引く
+fn lookup<'a>(
+    table: &'a [(&'static str, &'static str, &'static str)],
+    name: &str,
+) -> Option<&'a (&'static str, &'static str, &'static str)> {
+    table.iter().find(|names| names.0 == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{holidays, Date};
+
+    #[test]
+    fn every_holiday_has_names() {
+        for year in 1948..2031 {
+            for (date, name) in holidays(year).unwrap() {
+                assert!(Locale::English.holiday(&name).is_some(), "{}", date);
+                assert!(Locale::Kana.holiday(&name).is_some(), "{}", date);
+            }
+        }
+    }
+
+    #[test]
+    fn every_era_has_names() {
+        let mut date = Date::from_ymd(1868, 1, 25).unwrap();
+        while date.year() < 2030 {
+            let name = date.era().unwrap().name();
+            assert_eq!(Locale::Japanese.era(&name), Some(name.as_str()));
+            assert!(Locale::English.era(&name).is_some());
+            date = Date::from_ymd(date.year() + 1, 1, 1).unwrap();
+        }
+    }
+
+    #[test]
+    fn weekday_names() {
+        assert_eq!(Locale::Japanese.weekday(Weekday::Sunday), "日");
+        assert_eq!(
+            Locale::English.long_weekday(Weekday::Wednesday),
+            "Wednesday"
+        );
+        assert_eq!(Locale::Kana.long_weekday(Weekday::Saturday), "どようび");
+    }
+
+    #[test]
+    fn parse_tag() {
+        assert_eq!("ja".parse::<Locale>().unwrap(), Locale::Japanese);
+        assert_eq!("en-US".parse::<Locale>().unwrap(), Locale::English);
+        assert_eq!("EN".parse::<Locale>().unwrap(), Locale::English);
+        assert_eq!("kana".parse::<Locale>().unwrap(), Locale::Kana);
+        for locale in [Locale::Japanese, Locale::English, Locale::Kana].iter() {
+            assert_eq!(locale.tag().parse::<Locale>().unwrap(), *locale);
+        }
+
+        let err = "fr".parse::<Locale>().unwrap_err();
+        assert!(matches!(err, KoyomiError::UnknownLocale(ref s) if s == "fr"));
+        assert_eq!(err.to_string(), "対応していない言語です: fr");
+    }
+}
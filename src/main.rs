@@ -0,0 +1,349 @@
+//! # koyomi コマンド
+//!
+//! ライブラリの機能をコマンドラインから利用する。
+//!
+//! - カレンダーの表示
+//! - 日付の和暦・曜日・祝祭日の表示
+//! - 祝祭日の一覧
+//! - 和暦と西暦の相互変換
+//! - 営業日数の計算
+use std::env;
+use std::io::{self, IsTerminal};
+use std::process;
+
+use koyomi::{format, Calendar, Date, KoyomiError, KoyomiResult, Renderer, Weekday};
+
+/// 使い方
+const USAGE: &str = "Usage: koyomi <command> [options]
+
+Commands:
+  cal [YEAR|YEAR-MM] [-3] [--monday] [--plain]
+                             Print a calendar (default: this month)
+  date [DATE]                Show era, weekday and holiday of a date (default: today)
+  holidays [YEAR]            List holidays of a year (default: this year)
+  convert DATE               Convert between 西暦 (2020-05-01) and 和暦 (令和2年5月1日)
+  business-days FROM [UNTIL] Count business days (e.g. 2018, 2018-04)
+
+Options:
+  --json                     Print JSON instead of text
+  -h, --help                 Print this help";
+
+/// コマンドの実行結果
+type CliResult = Result<String, CliError>;
+
+/// コマンドのエラー
+#[derive(Debug)]
+enum CliError {
+    /// 引数の指定が誤っている
+    Usage(String),
+    /// ライブラリでエラーが発生した
+    Koyomi(KoyomiError),
+}
+
+impl From<KoyomiError> for CliError {
+    fn from(err: KoyomiError) -> Self {
+        CliError::Koyomi(err)
+    }
+}
+
+/// コマンドライン引数
+struct Args {
+    /// オプション以外の引数
+    values: Vec<String>,
+    /// JSON で出力するかどうか
+    json: bool,
+    /// 3ヶ月表示かどうか
+    three: bool,
+    /// 月曜始まりかどうか
+    monday: bool,
+    /// 色付けしないかどうか
+    plain: bool,
+}
+
+impl Args {
+    /// コマンドライン引数を解析する
+    fn parse(args: &[String]) -> Result<Self, CliError> {
+        let mut parsed = Args {
+            values: Vec::new(),
+            json: false,
+            three: false,
+            monday: false,
+            plain: false,
+        };
+
+        for arg in args {
+            match arg.as_str() {
+                "--json" => parsed.json = true,
+                "-3" => parsed.three = true,
+                "--monday" => parsed.monday = true,
+                "--plain" => parsed.plain = true,
+                a if a.starts_with('-') && a.len() > 1 => {
+                    return Err(CliError::Usage(format!("unknown option: {}", a)));
+                }
+                a => parsed.values.push(a.into()),
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// 位置引数を返す
+    fn value(&self, index: usize) -> Option<&str> {
+        self.values.get(index).map(|v| v.as_str())
+    }
+
+    /// 位置引数の数が上限以内かを検証する
+    fn at_most(&self, n: usize) -> Result<(), CliError> {
+        if self.values.len() > n {
+            Err(CliError::Usage(format!(
+                "unexpected argument: {}",
+                self.values[n]
+            )))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    match run(&args) {
+        Ok(out) => print!("{}", out),
+        Err(CliError::Usage(msg)) => {
+            eprintln!("koyomi: {}\n\n{}", msg, USAGE);
+            process::exit(2);
+        }
+        Err(CliError::Koyomi(err)) => {
+            eprintln!("koyomi: {}", err);
+            process::exit(1);
+        }
+    }
+}
+
+/// サブコマンドを実行する
+fn run(args: &[String]) -> CliResult {
+    let command = match args.first() {
+        None => return Err(CliError::Usage("no command given".into())),
+        Some(c) => c.as_str(),
+    };
+    if command == "-h" || command == "--help" {
+        return Ok(format!("{}\n", USAGE));
+    }
+
+    let args = Args::parse(&args[1..])?;
+    match command {
+        "cal" => cal(&args),
+        "date" => date(&args),
+        "holidays" => holidays(&args),
+        "convert" => convert(&args),
+        "business-days" => business_days(&args),
+        c => Err(CliError::Usage(format!("unknown command: {}", c))),
+    }
+}
+
+/// カレンダーを表示する
+fn cal(args: &Args) -> CliResult {
+    args.at_most(1)?;
+
+    let today = today();
+    let single = match args.value(0) {
+        Some(s) => s.to_owned(),
+        None => format!("{}-{:02}", today.year(), today.month()),
+    };
+    let is_year = !single.contains('-');
+
+    let calendar = if args.three && !is_year {
+        let base = Calendar::build().single(&single).finalize()?;
+        let first = Date::parse(&base.from())?;
+        let (from, until) = (previous_month(&first)?, next_month(&first)?);
+        Calendar::build().from(&from).until(&until).finalize()?
+    } else {
+        Calendar::build().single(&single).finalize()?
+    };
+
+    if args.json {
+        let days = calendar.iter().map(|d| date_json(&d)).collect::<Vec<_>>();
+        return Ok(format!("[{}]\n", days.join(",")));
+    }
+
+    let first = if args.monday {
+        Weekday::Monday
+    } else {
+        Weekday::Sunday
+    };
+    let columns = if is_year || args.three { 3 } else { 1 };
+    let color = !args.plain && io::stdout().is_terminal();
+    Ok(Renderer::new()
+        .color(color)
+        .columns(columns)
+        .first_weekday(first)
+        .render(&calendar)?)
+}
+
+/// 日付の和暦・曜日・祝祭日を表示する
+fn date(args: &Args) -> CliResult {
+    args.at_most(1)?;
+
+    let date = match args.value(0) {
+        Some(s) => Date::parse(s)?,
+        None => today(),
+    };
+
+    if args.json {
+        return Ok(format!("{}\n", date_json(&date)));
+    }
+
+    let mut out = format!("{} ({})\n", date, date.weekday().japanese());
+    if let Ok(wareki) = date.format(format::ERA) {
+        out.push_str(&format!("和暦: {}\n", wareki));
+    }
+    if let Some(holiday) = date.holiday() {
+        out.push_str(&format!("祝日: {}\n", holiday));
+    }
+    Ok(out)
+}
+
+/// 指定年の祝祭日を一覧表示する
+fn holidays(args: &Args) -> CliResult {
+    args.at_most(1)?;
+
+    let year = match args.value(0) {
+        Some(y) => y
+            .parse()
+            .map_err(|_| CliError::Usage(format!("invalid year: {}", y)))?,
+        None => today().year(),
+    };
+    let holidays = koyomi::holidays(year)?;
+
+    if args.json {
+        let items = holidays
+            .iter()
+            .map(|(d, name)| {
+                format!(
+                    "{{\"date\":{},\"weekday\":{},\"name\":{}}}",
+                    json_str(&d.to_string()),
+                    json_str(&d.weekday().japanese().to_string()),
+                    json_str(name)
+                )
+            })
+            .collect::<Vec<_>>();
+        return Ok(format!("[{}]\n", items.join(",")));
+    }
+
+    Ok(holidays
+        .iter()
+        .map(|(d, name)| format!("{} ({}) {}\n", d, d.weekday().japanese(), name))
+        .collect())
+}
+
+/// 和暦と西暦を相互に変換する
+fn convert(args: &Args) -> CliResult {
+    args.at_most(1)?;
+
+    let input = args
+        .value(0)
+        .ok_or_else(|| CliError::Usage("convert needs a date".into()))?;
+    let date = Date::parse(input)?;
+    let wareki = date.format(format::ERA).ok();
+
+    if args.json {
+        return Ok(format!(
+            "{{\"date\":{},\"wareki\":{}}}\n",
+            json_str(&date.to_string()),
+            json_opt(wareki.as_deref())
+        ));
+    }
+
+    // 和暦で指定した場合は西暦に、それ以外は和暦に変換する
+    if Date::parse_from_str(input, format::ERA).is_ok() {
+        Ok(format!("{}\n", date))
+    } else {
+        match wareki {
+            Some(w) => Ok(format!("{}\n", w)),
+            None => Err(KoyomiError::InvalidFormat(input.into()).into()),
+        }
+    }
+}
+
+/// 期間内の営業日数を表示する
+fn business_days(args: &Args) -> CliResult {
+    args.at_most(2)?;
+
+    let calendar = match (args.value(0), args.value(1)) {
+        (Some(single), None) => Calendar::build().single(single).finalize()?,
+        (Some(from), Some(until)) => Calendar::build().from(from).until(until).finalize()?,
+        _ => return Err(CliError::Usage("business-days needs a range".into())),
+    };
+    let days = calendar.iter().filter(|d| d.is_business_day()).count();
+
+    if args.json {
+        return Ok(format!(
+            "{{\"from\":{},\"until\":{},\"business_days\":{}}}\n",
+            json_str(&calendar.from()),
+            json_str(&calendar.until()),
+            days
+        ));
+    }
+
+    Ok(format!("{}\n", days))
+}
+
+/// 日本時間での今日の日付を返す
+fn today() -> Date {
+    Date::today_jst()
+}
+
+/// 前月の `Y-m` 表現を返す
+fn previous_month(date: &Date) -> KoyomiResult<String> {
+    let last = Date::from_ymd(date.year(), date.month(), 1)?.yesterday()?;
+    Ok(format!("{}-{:02}", last.year(), last.month()))
+}
+
+/// 翌月の `Y-m` 表現を返す
+fn next_month(date: &Date) -> KoyomiResult<String> {
+    let (y, m) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    Ok(format!("{}-{:02}", y, m))
+}
+
+/// 日付の情報を JSON にする
+fn date_json(date: &Date) -> String {
+    let era = date.era();
+    format!(
+        "{{\"date\":{},\"weekday\":{},\"era\":{},\"era_year\":{},\"wareki\":{},\"holiday\":{},\"business_day\":{}}}",
+        json_str(&date.to_string()),
+        json_str(&date.weekday().japanese().to_string()),
+        json_opt(era.as_ref().map(|e| e.name()).as_deref()),
+        era.as_ref()
+            .map_or("null".to_owned(), |e| e.year().to_string()),
+        json_opt(date.format(format::ERA).ok().as_deref()),
+        json_opt(date.holiday().as_deref()),
+        date.is_business_day()
+    )
+}
+
+/// 文字列を JSON の文字列リテラルにする
+fn json_str(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// 値がなければ `null` となる JSON の文字列リテラルにする
+fn json_opt(s: Option<&str>) -> String {
+    s.map_or("null".to_owned(), json_str)
+}
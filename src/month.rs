@@ -0,0 +1,323 @@
+//! # 月表示
+//!
+//! 1ヶ月分の日付を週ごとの行に分けたカレンダー。
+//! 先頭・末尾の週は前後の月の日付で埋める。
+#[cfg(feature = "serde")]
+use core::convert::TryFrom;
+
+use alloc::string::String;
+#[cfg(feature = "serde")]
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::calendar::num_days;
+use crate::{Date, KoyomiError, KoyomiResult, Weekday};
+
+/// 1週間の日数
+const ONE_WEEK: usize = 7;
+
+/// 月表示の1日分のセル
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Cell {
+    date: Date,
+    holiday: Option<String>,
+    in_month: bool,
+}
+
+impl Cell {
+    /// セルの日付を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{MonthGrid, Weekday};
+    ///
+    /// let grid = MonthGrid::new(2018, 4, Weekday::Sunday).unwrap();
+    /// assert_eq!(grid.weeks()[0][0].date().to_string(), "2018-04-01");
+    /// ```
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// セルの日付の祝祭日を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{MonthGrid, Weekday};
+    ///
+    /// let grid = MonthGrid::new(2018, 4, Weekday::Sunday).unwrap();
+    /// assert_eq!(grid.weeks()[4][1].holiday().unwrap(), "振替休日");
+    /// ```
+    pub fn holiday(&self) -> Option<String> {
+        self.holiday.clone()
+    }
+
+    /// セルの日付が表示対象の月に含まれるかどうかを判定する
+    /// 前後の月で埋めたセルは `false` となる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{MonthGrid, Weekday};
+    ///
+    /// let grid = MonthGrid::new(2018, 4, Weekday::Monday).unwrap();
+    /// assert!(!grid.weeks()[0][0].in_month());
+    /// assert!(grid.weeks()[0][6].in_month());
+    /// ```
+    pub fn in_month(&self) -> bool {
+        self.in_month
+    }
+
+    /// セルの日付が祝祭日かどうかを判定する
+    pub fn is_holiday(&self) -> bool {
+        self.holiday.is_some()
+    }
+
+    /// セルの日付が土曜・日曜かどうかを判定する
+    pub fn is_weekend(&self) -> bool {
+        matches!(self.date.weekday(), Weekday::Saturday | Weekday::Sunday)
+    }
+}
+
+/// 月表示
+///
+/// 指定した曜日を週の始まりとして、1ヶ月分の日付を週ごとに並べる。
+/// 日本の壁掛けカレンダーは日曜始まり、ISO 8601 は月曜始まりとなる。
+///
+/// デシリアライズ時は年・月・週の始まりの曜日から週ごとの行を作り直す。
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Month"))]
+pub struct MonthGrid {
+    year: i32,
+    month: u32,
+    first: Weekday,
+    weeks: Vec<Vec<Cell>>,
+}
+
+/// デシリアライズ時に週ごとの行を作り直すための中間表現
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct Month {
+    year: i32,
+    month: u32,
+    first: Weekday,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Month> for MonthGrid {
+    type Error = String;
+
+    fn try_from(month: Month) -> Result<Self, Self::Error> {
+        MonthGrid::new(month.year, month.month, month.first).map_err(|e| e.to_string())
+    }
+}
+
+impl MonthGrid {
+    /// 月表示オブジェクトを生成する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{MonthGrid, Weekday};
+    ///
+    /// let grid = MonthGrid::new(2018, 4, Weekday::Sunday);
+    /// assert!(grid.is_ok());
+    ///
+    /// let grid = MonthGrid::new(2018, 13, Weekday::Sunday);
+    /// assert!(grid.is_err());
+    ///
+    /// // 前後の月で埋める日付が扱える範囲外となる場合もエラーとなる
+    /// let grid = MonthGrid::new(262142, 12, Weekday::Sunday);
+    /// assert!(grid.is_err());
+    /// ```
+    pub fn new(year: i32, month: u32, first: Weekday) -> KoyomiResult<Self> {
+        if !(1..=12).contains(&month) {
+            return Err(KoyomiError::InvalidMonth(month));
+        }
+
+        let head = Date::from_ymd(year, month, 1)?;
+        let offset = head.weekday().days_since(first) as usize;
+        let days = offset + num_days(year, month) as usize;
+        let rows = days.div_ceil(ONE_WEEK);
+        let mut weeks = Vec::with_capacity(rows);
+        for row in 0..rows {
+            let mut week = Vec::with_capacity(ONE_WEEK);
+            for col in 0..ONE_WEEK {
+                let date = head.shift((row * ONE_WEEK + col) as i64 - offset as i64)?;
+                week.push(Cell {
+                    holiday: date.holiday(),
+                    in_month: date.year() == year && date.month() == month,
+                    date,
+                });
+            }
+            weeks.push(week);
+        }
+
+        Ok(MonthGrid {
+            year,
+            month,
+            first,
+            weeks,
+        })
+    }
+
+    /// 週の始まりの曜日を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{MonthGrid, Weekday};
+    ///
+    /// let grid = MonthGrid::new(2018, 4, Weekday::Sunday).unwrap();
+    /// assert_eq!(grid.first_weekday(), Weekday::Sunday);
+    /// ```
+    pub fn first_weekday(&self) -> Weekday {
+        self.first
+    }
+
+    /// 「月」を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{MonthGrid, Weekday};
+    ///
+    /// let grid = MonthGrid::new(2018, 4, Weekday::Sunday).unwrap();
+    /// assert_eq!(grid.month(), 4);
+    /// ```
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// 週の始まりから並べた曜日を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{MonthGrid, Weekday};
+    ///
+    /// let grid = MonthGrid::new(2018, 4, Weekday::Sunday).unwrap();
+    /// let header = grid.weekdays().iter().map(|w| w.japanese()).collect::<String>();
+    /// assert_eq!(header, "日月火水木金土");
+    /// ```
+    pub fn weekdays(&self) -> Vec<Weekday> {
+        self.weeks[0].iter().map(|c| c.date().weekday()).collect()
+    }
+
+    /// 週ごとの行を返す
+    /// 各行は必ず7日分のセルを持つ
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{MonthGrid, Weekday};
+    ///
+    /// let grid = MonthGrid::new(2018, 4, Weekday::Sunday).unwrap();
+    /// assert_eq!(grid.weeks().len(), 5);
+    ///
+    /// let grid = MonthGrid::new(2018, 4, Weekday::Monday).unwrap();
+    /// assert_eq!(grid.weeks().len(), 6);
+    /// ```
+    pub fn weeks(&self) -> &[Vec<Cell>] {
+        &self.weeks
+    }
+
+    /// 「年」を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{MonthGrid, Weekday};
+    ///
+    /// let grid = MonthGrid::new(2018, 4, Weekday::Sunday).unwrap();
+    /// assert_eq!(grid.year(), 2018);
+    /// ```
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_month() {
+        assert!(MonthGrid::new(2018, 0, Weekday::Sunday).is_err());
+        assert!(MonthGrid::new(2018, 13, Weekday::Sunday).is_err());
+    }
+
+    #[test]
+    fn sunday_first() {
+        let grid = MonthGrid::new(2018, 5, Weekday::Sunday).unwrap();
+        let weeks = grid.weeks();
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(weeks[0][0].date().to_string(), "2018-04-29");
+        assert_eq!(weeks[0][2].date().to_string(), "2018-05-01");
+        assert_eq!(weeks[4][6].date().to_string(), "2018-06-02");
+    }
+
+    #[test]
+    fn monday_first() {
+        let grid = MonthGrid::new(2018, 5, Weekday::Monday).unwrap();
+        let weeks = grid.weeks();
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(weeks[0][0].date().to_string(), "2018-04-30");
+        assert_eq!(weeks[0][1].date().to_string(), "2018-05-01");
+        assert_eq!(weeks[4][6].date().to_string(), "2018-06-03");
+    }
+
+    #[test]
+    fn exact_weeks() {
+        // 2015年2月は日曜始まりでちょうど4週となる
+        let grid = MonthGrid::new(2015, 2, Weekday::Sunday).unwrap();
+        assert_eq!(grid.weeks().len(), 4);
+        assert!(grid.weeks().iter().flatten().all(|c| c.in_month()));
+    }
+
+    #[test]
+    fn padding_cells() {
+        let grid = MonthGrid::new(2018, 5, Weekday::Sunday).unwrap();
+        let weeks = grid.weeks();
+        assert!(!weeks[0][1].in_month());
+        assert!(weeks[0][2].in_month());
+        assert!(weeks[4][4].in_month());
+        assert!(!weeks[4][5].in_month());
+    }
+
+    #[test]
+    fn weekend_and_holiday() {
+        let grid = MonthGrid::new(2018, 5, Weekday::Sunday).unwrap();
+        let weeks = grid.weeks();
+
+        // 2018-04-29 昭和の日(日曜)
+        assert!(weeks[0][0].is_weekend());
+        assert!(weeks[0][0].is_holiday());
+        // 2018-04-30 振替休日
+        assert!(!weeks[0][1].is_weekend());
+        assert_eq!(weeks[0][1].holiday().unwrap(), "振替休日");
+        // 2018-05-05 こどもの日(土曜)
+        assert!(weeks[0][6].is_weekend());
+        assert_eq!(weeks[0][6].holiday().unwrap(), "こどもの日");
+        // 2018-05-07 平日
+        assert!(!weeks[1][1].is_weekend());
+        assert!(!weeks[1][1].is_holiday());
+    }
+
+    #[test]
+    fn weekdays_header() {
+        let grid = MonthGrid::new(2018, 5, Weekday::Monday).unwrap();
+        let header = grid
+            .weekdays()
+            .iter()
+            .map(|w| w.japanese())
+            .collect::<String>();
+        assert_eq!(header, "月火水木金土日");
+    }
+}
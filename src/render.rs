@@ -0,0 +1,357 @@
+//! # 端末表示
+//!
+//! `cal` コマンドと同じレイアウトでカレンダーを文字列にする。
+//! 日曜・祝祭日は赤、土曜は青で ANSI エスケープシーケンスを使って色付けできる。
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::{Calendar, KoyomiResult, MonthGrid, Weekday};
+
+/// 日曜・祝祭日の色(赤)
+const RED: &str = "\x1b[31m";
+
+/// 土曜の色(青)
+const BLUE: &str = "\x1b[34m";
+
+/// 色指定の解除
+const RESET: &str = "\x1b[0m";
+
+/// 1ヶ月分の表示幅(2桁の日付7日分と区切りの空白6つ)
+const MONTH_WIDTH: usize = 20;
+
+/// 1ヶ月分の最大の週数
+const MAX_WEEKS: usize = 6;
+
+/// 月を横に並べるときの間隔
+const GUTTER: &str = "  ";
+
+/// カレンダーの表示形式
+///
+/// 既定では日曜始まり、1ヶ月ずつ縦に並べて色付きで表示する。
+///
+/// # Examples
+///
+/// ```rust
+/// use koyomi::{Calendar, Renderer};
+///
+/// let cal = Calendar::build().single("2025-05").finalize().unwrap();
+/// let text = Renderer::new().color(false).render(&cal).unwrap();
+/// assert_eq!(text.lines().next().unwrap(), "    令和7年 5月");
+/// assert_eq!(text.lines().nth(1).unwrap(), "日 月 火 水 木 金 土");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Renderer {
+    color: bool,
+    columns: usize,
+    first: Weekday,
+}
+
+impl Renderer {
+    /// 既定の表示形式を生成する
+    pub fn new() -> Self {
+        Renderer {
+            color: true,
+            columns: 1,
+            first: Weekday::Sunday,
+        }
+    }
+
+    /// 色付けするかどうかを指定する
+    /// `false` の場合はプレーンテキストとなる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Calendar, Renderer};
+    ///
+    /// let cal = Calendar::build().single("2025-05").finalize().unwrap();
+    /// let text = Renderer::new().color(false).render(&cal).unwrap();
+    /// assert!(!text.contains('\x1b'));
+    /// ```
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// 横に並べる月の数を指定する
+    /// 3を指定すると3ヶ月表示や1年表示(3ヶ月×4段)になる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Calendar, Renderer};
+    ///
+    /// let cal = Calendar::build().single("2025").finalize().unwrap();
+    /// let text = Renderer::new().color(false).columns(3).render(&cal).unwrap();
+    /// assert!(text.lines().next().unwrap().contains("3月"));
+    /// ```
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// 週の始まりの曜日を指定する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Calendar, Renderer, Weekday};
+    ///
+    /// let cal = Calendar::build().single("2025-05").finalize().unwrap();
+    /// let text = Renderer::new()
+    ///     .color(false)
+    ///     .first_weekday(Weekday::Monday)
+    ///     .render(&cal)
+    ///     .unwrap();
+    /// assert_eq!(text.lines().nth(1).unwrap(), "月 火 水 木 金 土 日");
+    /// ```
+    pub fn first_weekday(mut self, first: Weekday) -> Self {
+        self.first = first;
+        self
+    }
+
+    /// カレンダーの期間に含まれる月をすべて表示する
+    /// 前後の月で埋める日付が扱える範囲外となる月を含む場合はエラーとなる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Calendar, Renderer};
+    ///
+    /// let cal = Calendar::build()
+    ///     .from("2025-04")
+    ///     .until("2025-06")
+    ///     .finalize()
+    ///     .unwrap();
+    /// let text = Renderer::new().columns(3).render(&cal).unwrap();
+    /// assert!(text.contains("令和7年 4月"));
+    /// assert!(text.contains("令和7年 6月"));
+    /// ```
+    pub fn render(&self, calendar: &Calendar) -> KoyomiResult<String> {
+        let months = calendar.months(self.first)?;
+        let blocks = months
+            .chunks(self.columns)
+            .map(|row| self.render_row(row))
+            .collect::<Vec<_>>();
+
+        Ok(blocks.join("\n"))
+    }
+
+    /// 1ヶ月分を表示する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{MonthGrid, Renderer, Weekday};
+    ///
+    /// let grid = MonthGrid::new(2025, 5, Weekday::Sunday).unwrap();
+    /// let text = Renderer::new().color(false).render_month(&grid);
+    /// assert_eq!(text.lines().nth(2).unwrap(), "             1  2  3");
+    /// ```
+    pub fn render_month(&self, grid: &MonthGrid) -> String {
+        self.render_row(core::slice::from_ref(grid))
+    }
+
+    /// 横に並べる月をまとめて表示する
+    fn render_row(&self, grids: &[MonthGrid]) -> String {
+        let blocks = grids
+            .iter()
+            .map(|g| self.month_lines(g))
+            .collect::<Vec<_>>();
+        let height = blocks.iter().map(|b| b.len()).max().unwrap_or(0);
+
+        let mut text = String::new();
+        for i in 0..height {
+            let line = blocks
+                .iter()
+                .map(|b| match b.get(i) {
+                    Some(l) => l.to_owned(),
+                    None => " ".repeat(MONTH_WIDTH),
+                })
+                .collect::<Vec<_>>()
+                .join(GUTTER);
+            text.push_str(line.trim_end());
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// 1ヶ月分の行(見出し・曜日・週)を返す
+    fn month_lines(&self, grid: &MonthGrid) -> Vec<String> {
+        let mut lines = Vec::with_capacity(MAX_WEEKS + 2);
+
+        lines.push(center(&title(grid), MONTH_WIDTH));
+
+        let header = grid
+            .weekdays()
+            .iter()
+            .map(|w| self.paint(&w.japanese().to_string(), *w, false))
+            .collect::<Vec<_>>()
+            .join(" ");
+        lines.push(header);
+
+        for week in grid.weeks() {
+            let line = week
+                .iter()
+                .map(|c| {
+                    if c.in_month() {
+                        let day = format!("{:>2}", c.date().day());
+                        self.paint(&day, c.date().weekday(), c.is_holiday())
+                    } else {
+                        "  ".to_owned()
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+            lines.push(line);
+        }
+
+        lines
+    }
+
+    /// 曜日と祝祭日に応じて色付けする
+    fn paint(&self, text: &str, weekday: Weekday, holiday: bool) -> String {
+        if !self.color {
+            return text.to_owned();
+        }
+
+        match weekday {
+            Weekday::Sunday => format!("{}{}{}", RED, text, RESET),
+            _ if holiday => format!("{}{}{}", RED, text, RESET),
+            Weekday::Saturday => format!("{}{}{}", BLUE, text, RESET),
+            _ => text.to_owned(),
+        }
+    }
+}
+
+impl Default for Renderer {
+    fn default() -> Self {
+        Renderer::new()
+    }
+}
+
+/// 月表示の見出しを返す
+/// 和暦がない年は西暦で表示する
+fn title(grid: &MonthGrid) -> String {
+    let first = grid.weeks()[0]
+        .iter()
+        .find(|c| c.in_month())
+        .expect("Empty month!")
+        .date();
+    match first.era() {
+        Some(era) => format!("{} {}月", era.format(), grid.month()),
+        None => format!("{}年 {}月", grid.year(), grid.month()),
+    }
+}
+
+/// 表示幅に合わせて中央寄せする
+fn center(text: &str, width: usize) -> String {
+    let len = display_width(text);
+    if len >= width {
+        return text.to_owned();
+    }
+
+    let left = (width - len) / 2;
+    let right = width - len - left;
+    format!("{}{}{}", " ".repeat(left), text, " ".repeat(right))
+}
+
+/// 端末での表示幅を返す(全角文字は2桁として数える)
+fn display_width(text: &str) -> usize {
+    text.chars().map(|c| if c.is_ascii() { 1 } else { 2 }).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(from: &str, until: &str, columns: usize) -> String {
+        let cal = Calendar::build()
+            .from(from)
+            .until(until)
+            .finalize()
+            .unwrap();
+        Renderer::new()
+            .color(false)
+            .columns(columns)
+            .render(&cal)
+            .unwrap()
+    }
+
+    #[test]
+    fn single_month() {
+        let text = plain("2025-05", "2025-05", 1);
+        let expected = "    令和7年 5月
+日 月 火 水 木 金 土
+             1  2  3
+ 4  5  6  7  8  9 10
+11 12 13 14 15 16 17
+18 19 20 21 22 23 24
+25 26 27 28 29 30 31
+";
+        assert_eq!(text, expected);
+    }
+
+    #[test]
+    fn monday_first() {
+        let cal = Calendar::build().single("2025-06").finalize().unwrap();
+        let text = Renderer::new()
+            .color(false)
+            .first_weekday(Weekday::Monday)
+            .render(&cal)
+            .unwrap();
+        let lines = text.lines().collect::<Vec<_>>();
+        assert_eq!(lines[1], "月 火 水 木 金 土 日");
+        assert_eq!(lines[2], "                   1");
+        assert_eq!(lines[7], "30");
+    }
+
+    #[test]
+    fn three_months() {
+        let text = plain("2025-04", "2025-06", 3);
+        let lines = text.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(
+            lines[0],
+            "    令和7年 4月           令和7年 5月           令和7年 6月"
+        );
+        assert_eq!(
+            lines[2],
+            "       1  2  3  4  5               1  2  3   1  2  3  4  5  6  7"
+        );
+    }
+
+    #[test]
+    fn full_year() {
+        let text = plain("2025", "2025", 3);
+        let titles = text.lines().filter(|l| l.contains('年')).count();
+        assert_eq!(titles, 4);
+        assert!(text.contains("令和7年 12月"));
+    }
+
+    #[test]
+    fn without_era() {
+        let text = plain("1800-01", "1800-01", 1);
+        assert!(text.starts_with("     1800年 1月"));
+    }
+
+    #[test]
+    fn colored() {
+        let cal = Calendar::build().single("2025-05").finalize().unwrap();
+        let text = Renderer::new().render(&cal).unwrap();
+        // 日曜
+        assert!(text.contains("\x1b[31m 4\x1b[0m"));
+        // 土曜
+        assert!(text.contains("\x1b[34m10\x1b[0m"));
+        // 祝日(5月3日憲法記念日・土曜)
+        assert!(text.contains("\x1b[31m 3\x1b[0m"));
+        // 祝日(5月5日こどもの日・月曜)
+        assert!(text.contains("\x1b[31m 5\x1b[0m"));
+        // 平日
+        assert!(text.contains(" 7"));
+        assert!(!text.contains("\x1b[31m 7\x1b[0m"));
+    }
+}
@@ -0,0 +1,370 @@
+//! # 学年
+//!
+//! 生年月日から就学する学年を導出する。
+//!
+//! 年齢計算ニ関スル法律により、年齢は誕生日の前日に加算される。
+//! そのため4月1日生まれは3月31日に満6歳となり、
+//! 4月2日以降に生まれた子どもより1年早い学年となる(早生まれ)。
+use core::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Date, KoyomiError, KoyomiResult};
+
+/// 学年の始まり(4月1日)
+const BEGINNING: (u32, u32) = (4, 1);
+
+/// 小学校に入学する年齢
+const ENTRY_AGE: i32 = 6;
+
+/// 生年月日から学年を導出する
+///
+/// # Examples
+///
+/// ```rust
+/// use koyomi::{cohort, Date};
+///
+/// // 4月1日生まれは早生まれ
+/// let birthday = Date::from_ymd(2012, 4, 1).unwrap();
+/// assert_eq!(cohort(&birthday).entry_year(), 2018);
+///
+/// let birthday = Date::from_ymd(2012, 4, 2).unwrap();
+/// assert_eq!(cohort(&birthday).entry_year(), 2019);
+/// ```
+pub fn cohort(birthday: &Date) -> Cohort {
+    let early = (birthday.month(), birthday.day()) <= BEGINNING;
+    let entry = if early {
+        birthday.year() + ENTRY_AGE
+    } else {
+        birthday.year() + ENTRY_AGE + 1
+    };
+
+    Cohort { entry }
+}
+
+/// 学校種別
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum School {
+    /// 小学校
+    Elementary,
+    /// 中学校
+    JuniorHigh,
+    /// 高等学校
+    High,
+}
+
+impl School {
+    /// 学校種別の日本語表現を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::School;
+    ///
+    /// assert_eq!(School::Elementary.japanese(), "小学校");
+    /// ```
+    pub fn japanese(&self) -> &'static str {
+        match *self {
+            School::Elementary => "小学校",
+            School::JuniorHigh => "中学校",
+            School::High => "高等学校",
+        }
+    }
+
+    /// 学年表記で使う略称を返す
+    fn abbreviation(&self) -> char {
+        match *self {
+            School::Elementary => '小',
+            School::JuniorHigh => '中',
+            School::High => '高',
+        }
+    }
+
+    /// 小学校入学から数えて、入学までの年数と修業年限を返す
+    fn term(&self) -> (i32, i32) {
+        match *self {
+            School::Elementary => (0, 6),
+            School::JuniorHigh => (6, 3),
+            School::High => (9, 3),
+        }
+    }
+}
+
+/// 学年
+///
+/// 学校種別と、その学校での年次を持つ。
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Grade {
+    school: School,
+    year: u32,
+}
+
+impl Grade {
+    /// 学校種別を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{cohort, Date, School};
+    ///
+    /// let birthday = Date::from_ymd(2012, 4, 1).unwrap();
+    /// let today = Date::from_ymd(2024, 4, 1).unwrap();
+    /// let grade = cohort(&birthday).grade(&today).unwrap();
+    /// assert_eq!(grade.school(), School::JuniorHigh);
+    /// ```
+    pub fn school(&self) -> School {
+        self.school
+    }
+
+    /// 学校での年次を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{cohort, Date};
+    ///
+    /// let birthday = Date::from_ymd(2012, 4, 1).unwrap();
+    /// let today = Date::from_ymd(2024, 4, 1).unwrap();
+    /// let grade = cohort(&birthday).grade(&today).unwrap();
+    /// assert_eq!(grade.year(), 1);
+    /// ```
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+}
+
+impl fmt::Display for Grade {
+    /// 学年の文字列表現を返す
+    /// フォーマットは「小1」「中3」などの略称となる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{cohort, Date};
+    ///
+    /// let birthday = Date::from_ymd(2012, 4, 2).unwrap();
+    /// let today = Date::from_ymd(2019, 4, 1).unwrap();
+    /// let grade = cohort(&birthday).grade(&today).unwrap();
+    /// assert_eq!(grade.to_string(), "小1");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.school.abbreviation(), self.year)
+    }
+}
+
+/// 同じ学年の集団
+///
+/// 4月2日から翌年4月1日までに生まれた子どもが同じ学年となる。
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Cohort {
+    entry: i32,
+}
+
+impl Cohort {
+    /// 小学校に入学する年を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{cohort, Date};
+    ///
+    /// let birthday = Date::from_ymd(2012, 12, 24).unwrap();
+    /// assert_eq!(cohort(&birthday).entry_year(), 2019);
+    /// ```
+    pub fn entry_year(&self) -> i32 {
+        self.entry
+    }
+
+    /// 入学日(入学年の4月1日)を返す
+    /// 扱える範囲外の日付となる場合はエラーとなる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{cohort, Date, School};
+    ///
+    /// let birthday = Date::from_ymd(2012, 12, 24).unwrap();
+    /// let entrance = cohort(&birthday).entrance(School::JuniorHigh).unwrap();
+    /// assert_eq!(entrance.to_string(), "2025-04-01");
+    ///
+    /// assert!(cohort(&Date::MAX).entrance(School::Elementary).is_err());
+    /// ```
+    pub fn entrance(&self, school: School) -> KoyomiResult<Date> {
+        let (offset, _) = school.term();
+        let (m, d) = BEGINNING;
+        self.date_after(offset, m, d)
+    }
+
+    /// 卒業日(学年の終わりとなる3月31日)を返す
+    /// 扱える範囲外の日付となる場合はエラーとなる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{cohort, Date, School};
+    ///
+    /// let birthday = Date::from_ymd(2012, 12, 24).unwrap();
+    /// let graduation = cohort(&birthday).graduation(School::Elementary).unwrap();
+    /// assert_eq!(graduation.to_string(), "2025-03-31");
+    /// ```
+    pub fn graduation(&self, school: School) -> KoyomiResult<Date> {
+        let (offset, term) = school.term();
+        self.date_after(offset + term, 3, 31)
+    }
+
+    /// 指定日時点の学年を返す
+    /// 未就学または高校卒業後は `None` となる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{cohort, Date};
+    ///
+    /// let c = cohort(&Date::from_ymd(2012, 4, 1).unwrap());
+    ///
+    /// let grade = c.grade(&Date::from_ymd(2018, 3, 31).unwrap());
+    /// assert!(grade.is_none());
+    ///
+    /// let grade = c.grade(&Date::from_ymd(2018, 4, 1).unwrap());
+    /// assert_eq!(grade.unwrap().to_string(), "小1");
+    /// ```
+    pub fn grade(&self, date: &Date) -> Option<Grade> {
+        let school_year = if (date.month(), date.day()) >= BEGINNING {
+            date.year()
+        } else {
+            date.year() - 1
+        };
+        let n = school_year.checked_sub(self.entry)?;
+
+        [School::Elementary, School::JuniorHigh, School::High]
+            .iter()
+            .find(|s| {
+                let (offset, term) = s.term();
+                offset <= n && n < offset + term
+            })
+            .map(|&school| Grade {
+                school,
+                year: (n - school.term().0 + 1) as u32,
+            })
+    }
+
+    /// 小学校の入学年から指定年数後の年の月日を返す
+    fn date_after(&self, years: i32, month: u32, day: u32) -> KoyomiResult<Date> {
+        let year = self
+            .entry
+            .checked_add(years)
+            .ok_or(KoyomiError::OutOfRange(self.entry, month, day))?;
+        Date::from_ymd(year, month, day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entry_year_of_early_birthday() {
+        let date = Date::parse("2012-01-01").unwrap();
+        assert_eq!(cohort(&date).entry_year(), 2018);
+
+        let date = Date::parse("2012-04-01").unwrap();
+        assert_eq!(cohort(&date).entry_year(), 2018);
+    }
+
+    #[test]
+    fn entry_year_of_late_birthday() {
+        let date = Date::parse("2012-04-02").unwrap();
+        assert_eq!(cohort(&date).entry_year(), 2019);
+
+        let date = Date::parse("2012-12-31").unwrap();
+        assert_eq!(cohort(&date).entry_year(), 2019);
+    }
+
+    #[test]
+    fn same_cohort() {
+        let d1 = Date::parse("2011-04-02").unwrap();
+        let d2 = Date::parse("2012-04-01").unwrap();
+        assert_eq!(cohort(&d1), cohort(&d2));
+    }
+
+    #[test]
+    fn grade_of_elementary() {
+        let c = cohort(&Date::parse("2012-04-01").unwrap());
+
+        let grade = c.grade(&Date::parse("2018-04-01").unwrap()).unwrap();
+        assert_eq!(grade.school(), School::Elementary);
+        assert_eq!(grade.year(), 1);
+
+        let grade = c.grade(&Date::parse("2024-03-31").unwrap()).unwrap();
+        assert_eq!(grade.to_string(), "小6");
+    }
+
+    #[test]
+    fn grade_of_junior_high() {
+        let c = cohort(&Date::parse("2012-04-01").unwrap());
+
+        let grade = c.grade(&Date::parse("2024-04-01").unwrap()).unwrap();
+        assert_eq!(grade.to_string(), "中1");
+
+        let grade = c.grade(&Date::parse("2027-03-31").unwrap()).unwrap();
+        assert_eq!(grade.to_string(), "中3");
+    }
+
+    #[test]
+    fn grade_of_high() {
+        let c = cohort(&Date::parse("2012-04-01").unwrap());
+
+        let grade = c.grade(&Date::parse("2027-04-01").unwrap()).unwrap();
+        assert_eq!(grade.to_string(), "高1");
+
+        let grade = c.grade(&Date::parse("2030-03-31").unwrap()).unwrap();
+        assert_eq!(grade.to_string(), "高3");
+    }
+
+    #[test]
+    fn grade_out_of_school() {
+        let c = cohort(&Date::parse("2012-04-01").unwrap());
+        assert!(c.grade(&Date::parse("2018-03-31").unwrap()).is_none());
+        assert!(c.grade(&Date::parse("2030-04-01").unwrap()).is_none());
+    }
+
+    #[test]
+    fn entrance_and_graduation() {
+        let c = cohort(&Date::parse("2012-04-01").unwrap());
+
+        let entrance = |s| c.entrance(s).unwrap().to_string();
+        let graduation = |s| c.graduation(s).unwrap().to_string();
+        assert_eq!(entrance(School::Elementary), "2018-04-01");
+        assert_eq!(graduation(School::Elementary), "2024-03-31");
+        assert_eq!(entrance(School::JuniorHigh), "2024-04-01");
+        assert_eq!(graduation(School::JuniorHigh), "2027-03-31");
+        assert_eq!(entrance(School::High), "2027-04-01");
+        assert_eq!(graduation(School::High), "2030-03-31");
+    }
+
+    #[test]
+    fn entrance_and_graduation_beyond_range() {
+        // 262136年4月2日以降に生まれると入学年が範囲外となる
+        let c = cohort(&Date::parse("262136-04-01").unwrap());
+        assert_eq!(
+            c.entrance(School::Elementary).unwrap().to_string(),
+            "262142-04-01"
+        );
+        assert!(c.graduation(School::Elementary).is_err());
+
+        let c = cohort(&Date::MAX);
+        assert_eq!(c.entry_year(), 262149);
+        assert!(c.entrance(School::Elementary).is_err());
+        assert!(c.graduation(School::High).is_err());
+        assert!(c.grade(&Date::MAX).is_none());
+
+        let c = Cohort { entry: i32::MAX };
+        assert!(c.entrance(School::High).is_err());
+        let c = Cohort { entry: i32::MIN };
+        assert!(c.grade(&Date::MAX).is_none());
+    }
+}
@@ -0,0 +1,243 @@
+//! # serde 対応
+//!
+//! `serde` フィーチャを有効にすると、公開している型をシリアライズ・デシリアライズできる。
+//!
+//! - `Date` は `YYYY-MM-DD` 形式の文字列
+//! - `Era` は元号名と和暦の年(`{"name":"令和","year":2}`)
+//! - `Calendar` は期間(`{"from":"2018-01-01","until":"2018-12-31"}`)
+//!
+//! 和暦・曜日・祝祭日も含めたい場合は [`rich`](rich/index.html) を使う。
+
+/// 和暦・曜日・祝祭日を含む `Date` の表現
+///
+/// `#[serde(with = "koyomi::serde::rich")]` を指定したフィールドは、
+/// 次のようなオブジェクトとなる。
+/// 和暦がない日付や祝祭日でない日付は `null` となる。
+///
+/// ```json
+/// {"date":"2019-05-01","weekday":"水","era":"令和","era_year":1,"holiday":"新天皇即位日"}
+/// ```
+///
+/// デシリアライズ時は `date` のみを使い、それ以外は日付から導出し直す。
+///
+/// # Examples
+///
+/// ```rust
+/// use koyomi::Date;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Event {
+///     #[serde(with = "koyomi::serde::rich")]
+///     date: Date,
+/// }
+///
+/// let event = Event {
+///     date: Date::from_ymd(2018, 1, 1).unwrap(),
+/// };
+/// let json = serde_json::to_string(&event).unwrap();
+/// assert_eq!(
+///     json,
+///     r#"{"date":{"date":"2018-01-01","weekday":"月","era":"平成","era_year":30,"holiday":"元日"}}"#
+/// );
+///
+/// let event: Event = serde_json::from_str(&json).unwrap();
+/// assert_eq!(event.date.to_string(), "2018-01-01");
+/// ```
+pub mod rich {
+    use alloc::string::{String, ToString};
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::Date;
+
+    /// シリアライズ時の表現
+    #[derive(Serialize)]
+    struct Rich {
+        date: Date,
+        weekday: String,
+        era: Option<String>,
+        era_year: Option<i32>,
+        holiday: Option<String>,
+    }
+
+    /// デシリアライズ時の表現
+    #[derive(Deserialize)]
+    struct Plain {
+        date: Date,
+    }
+
+    /// 和暦・曜日・祝祭日を含めてシリアライズする
+    pub fn serialize<S: Serializer>(date: &Date, serializer: S) -> Result<S::Ok, S::Error> {
+        let era = date.era();
+        Rich {
+            date: *date,
+            weekday: date.weekday().japanese().to_string(),
+            era: era.as_ref().map(|e| e.name()),
+            era_year: era.as_ref().map(|e| e.year()),
+            holiday: date.holiday(),
+        }
+        .serialize(serializer)
+    }
+
+    /// `date` フィールドからデシリアライズする
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Date, D::Error> {
+        Plain::deserialize(deserializer).map(|p| p.date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::serde::{Deserialize, Serialize};
+
+    use crate::*;
+
+    fn json<T: Serialize>(value: &T) -> String {
+        serde_json::to_string(value).unwrap()
+    }
+
+    fn round_trip<T>(value: &T) -> T
+    where
+        T: Serialize + for<'de> Deserialize<'de>,
+    {
+        serde_json::from_str(&json(value)).unwrap()
+    }
+
+    #[test]
+    fn date() {
+        let date = Date::from_ymd(2018, 1, 1).unwrap();
+        assert_eq!(json(&date), r#""2018-01-01""#);
+        assert_eq!(round_trip(&date), date);
+
+        let date = Date::from_ymd(-44, 3, 15).unwrap();
+        assert_eq!(json(&date), r#""-0044-03-15""#);
+
+        assert!(serde_json::from_str::<Date>(r#""2018-02-30""#).is_err());
+        assert!(serde_json::from_str::<Date>("20180101").is_err());
+    }
+
+    #[test]
+    fn date_edge_years() {
+        #[derive(Debug, Deserialize, PartialEq, Serialize)]
+        struct Wrapper {
+            #[serde(with = "super::rich")]
+            date: Date,
+        }
+
+        let dates = [
+            Date::from_ymd(999, 1, 1).unwrap(),
+            Date::from_ymd(0, 1, 1).unwrap(),
+            Date::from_ymd(-44, 3, 15).unwrap(),
+            Date::MIN,
+            Date::MAX,
+        ];
+        for &date in dates.iter() {
+            assert_eq!(round_trip(&date), date, "{}", date);
+            assert_eq!(round_trip(&Wrapper { date }).date, date, "{}", date);
+        }
+
+        let cal = Calendar::new(Date::MIN, Date::MAX).unwrap();
+        assert_eq!(
+            json(&cal),
+            r#"{"from":"-262143-01-01","until":"262142-12-31"}"#
+        );
+        let cal = round_trip(&cal);
+        assert_eq!(
+            (cal.from(), cal.until()),
+            (Date::MIN.to_string(), Date::MAX.to_string())
+        );
+    }
+
+    #[test]
+    fn weekday() {
+        assert_eq!(json(&Weekday::Monday), r#""Monday""#);
+        assert_eq!(round_trip(&Weekday::Sunday), Weekday::Sunday);
+    }
+
+    #[test]
+    fn era() {
+        let era = Date::from_ymd(2019, 5, 1).unwrap().era().unwrap();
+        assert_eq!(json(&era), r#"{"name":"令和","year":1}"#);
+        assert_eq!(round_trip(&era), era);
+
+        let era = Date::from_ymd(2019, 4, 30).unwrap().era().unwrap();
+        assert_eq!(round_trip(&era), era);
+
+        assert!(serde_json::from_str::<Era>(r#"{"name":"平成","year":32}"#).is_err());
+        assert!(serde_json::from_str::<Era>(r#"{"name":"江戸","year":1}"#).is_err());
+    }
+
+    #[test]
+    fn calendar() {
+        let cal = Calendar::build().single("2018-05").finalize().unwrap();
+        assert_eq!(json(&cal), r#"{"from":"2018-05-01","until":"2018-05-31"}"#);
+
+        let cal = round_trip(&cal);
+        assert_eq!(cal.from(), "2018-05-01");
+        assert_eq!(cal.until(), "2018-05-31");
+
+        let invalid = r#"{"from":"2018-05-31","until":"2018-05-01"}"#;
+        assert!(serde_json::from_str::<Calendar>(invalid).is_err());
+    }
+
+    #[test]
+    fn fiscal_year() {
+        let fy = FiscalYear::new(2024);
+        assert_eq!(json(&fy), r#"{"year":2024,"start":4}"#);
+        assert_eq!(round_trip(&fy), fy);
+        assert_eq!(json(&Half::First), r#""First""#);
+
+        let invalid = r#"{"year":2024,"start":13}"#;
+        assert!(serde_json::from_str::<FiscalYear>(invalid).is_err());
+    }
+
+    #[test]
+    fn iso_week() {
+        let week = IsoWeek::new(2020, 53).unwrap();
+        assert_eq!(json(&week), r#"{"year":2020,"week":53}"#);
+        assert_eq!(round_trip(&week), week);
+
+        let invalid = r#"{"year":2021,"week":53}"#;
+        assert!(serde_json::from_str::<IsoWeek>(invalid).is_err());
+    }
+
+    #[test]
+    fn month_grid() {
+        let grid = MonthGrid::new(2018, 5, Weekday::Sunday).unwrap();
+        assert_eq!(round_trip(&grid), grid);
+
+        let json = r#"{"year":2018,"month":5,"first":"Sunday","weeks":[]}"#;
+        let rebuilt = serde_json::from_str::<MonthGrid>(json).unwrap();
+        assert_eq!(rebuilt, grid);
+
+        let invalid = r#"{"year":2018,"month":13,"first":"Sunday"}"#;
+        assert!(serde_json::from_str::<MonthGrid>(invalid).is_err());
+    }
+
+    #[test]
+    fn school() {
+        let cohort = cohort(&Date::from_ymd(2012, 4, 1).unwrap());
+        assert_eq!(round_trip(&cohort), cohort);
+
+        let grade = cohort.grade(&Date::from_ymd(2018, 4, 1).unwrap()).unwrap();
+        assert_eq!(json(&grade), r#"{"school":"Elementary","year":1}"#);
+        assert_eq!(round_trip(&grade), grade);
+    }
+
+    #[test]
+    fn rich_without_era() {
+        #[derive(Serialize)]
+        struct Wrapper {
+            #[serde(with = "super::rich")]
+            date: Date,
+        }
+
+        let wrapper = Wrapper {
+            date: Date::from_ymd(1800, 1, 2).unwrap(),
+        };
+        assert_eq!(
+            json(&wrapper),
+            r#"{"date":{"date":"1800-01-02","weekday":"木","era":null,"era_year":null,"holiday":null}}"#
+        );
+    }
+}
@@ -0,0 +1,330 @@
+//! # 祝祭日テーブル
+//!
+//! 年ごとに祝祭日を事前計算して保持する。
+//! [`holiday`](fn.holiday.html) は判定のたびに規則を順に評価するため、
+//! 大量の日付を判定する場合はこのテーブルを使う。
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+
+use crate::holiday::{self, HolidaySource};
+use crate::{Date, Weekday};
+
+/// 1年の最大日数
+const MAX_DAYS: usize = 366;
+
+/// 1年分のビット列の長さ
+const WORDS: usize = MAX_DAYS.div_ceil(64);
+
+/// 祝祭日テーブル
+///
+/// 初めて参照された年の祝祭日を計算して保持する。
+/// 以降は、祝祭日かどうかをビット列の参照のみで判定できる。
+/// 複数のスレッドから共有して使うことができる。
+///
+/// # Examples
+///
+/// ```rust
+/// use koyomi::{Date, HolidayTable};
+///
+/// let table = HolidayTable::new();
+/// let date = Date::from_ymd(2018, 1, 8).unwrap();
+/// assert!(table.is_holiday(&date));
+/// assert_eq!(table.name(&date).unwrap(), "成人の日");
+/// assert!(!table.is_business_day(&date));
+/// ```
+#[derive(Debug, Default)]
+pub struct HolidayTable {
+    years: RwLock<BTreeMap<i32, Arc<Year>>>,
+}
+
+/// 1年分の祝祭日
+#[derive(Debug)]
+struct Year {
+    /// 元日を0とした通日ごとの、祝祭日かどうか
+    bits: [u64; WORDS],
+    /// 通日と祝祭日の名前(通日順)
+    names: Vec<(u16, String)>,
+}
+
+impl HolidayTable {
+    /// 空のテーブルを生成する
+    pub fn new() -> Self {
+        HolidayTable::default()
+    }
+
+    /// 指定年の祝祭日を事前に計算する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::HolidayTable;
+    ///
+    /// let table = HolidayTable::new();
+    /// table.preload(2018..=2020);
+    /// assert_eq!(table.years(), vec![2018, 2019, 2020]);
+    /// ```
+    pub fn preload<I: IntoIterator<Item = i32>>(&self, years: I) {
+        for year in years {
+            self.year(year);
+        }
+    }
+
+    /// 計算済みの年を返す
+    pub fn years(&self) -> Vec<i32> {
+        self.years
+            .read()
+            .expect("Poisoned holiday table!")
+            .keys()
+            .copied()
+            .collect()
+    }
+
+    /// 祝祭日かどうかを判定する
+    pub fn is_holiday(&self, date: &Date) -> bool {
+        let (y, n) = locate(date);
+        self.with_year(y, |year| year.contains(n))
+    }
+
+    /// 営業日(土曜・日曜・祝祭日以外)かどうかを判定する
+    pub fn is_business_day(&self, date: &Date) -> bool {
+        match date.weekday() {
+            Weekday::Saturday | Weekday::Sunday => false,
+            _ => !self.is_holiday(date),
+        }
+    }
+
+    /// 祝祭日であればその名前を返す
+    pub fn name(&self, date: &Date) -> Option<String> {
+        let (y, n) = locate(date);
+        self.with_year(y, |year| year.name(n))
+    }
+
+    /// 複数の日付の祝祭日をまとめて判定する
+    /// 結果は引数と同じ順に並ぶ
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Calendar, HolidayTable};
+    ///
+    /// let dates = Calendar::build().single("2018-01").finalize().unwrap().make();
+    /// let names = HolidayTable::new().names(&dates);
+    /// assert_eq!(names[0].as_deref(), Some("元日"));
+    /// assert_eq!(names[1], None);
+    /// assert_eq!(names.iter().flatten().count(), 2);
+    /// ```
+    pub fn names(&self, dates: &[Date]) -> Vec<Option<String>> {
+        self.batch(dates, |year, _, n| year.name(n))
+    }
+
+    /// 複数の日付が祝祭日かどうかをまとめて判定する
+    /// 結果は引数と同じ順に並ぶ
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Date, HolidayTable};
+    ///
+    /// let dates = [
+    ///     Date::from_ymd(2018, 1, 1).unwrap(),
+    ///     Date::from_ymd(2019, 1, 2).unwrap(),
+    ///     Date::from_ymd(2020, 1, 13).unwrap(),
+    /// ];
+    /// let table = HolidayTable::new();
+    /// assert_eq!(table.holidays(&dates), vec![true, false, true]);
+    /// ```
+    pub fn holidays(&self, dates: &[Date]) -> Vec<bool> {
+        self.batch(dates, |year, _, n| year.contains(n))
+    }
+
+    /// 複数の日付が営業日かどうかをまとめて判定する
+    /// 結果は引数と同じ順に並ぶ
+    pub fn business_days(&self, dates: &[Date]) -> Vec<bool> {
+        self.batch(dates, |year, date, n| match date.weekday() {
+            Weekday::Saturday | Weekday::Sunday => false,
+            _ => !year.contains(n),
+        })
+    }
+
+    /// 年ごとのテーブルを引きながら、日付ごとに判定する
+    /// 同じ年が続く間はテーブルを引き直さない
+    fn batch<T, F>(&self, dates: &[Date], f: F) -> Vec<T>
+    where
+        F: Fn(&Year, &Date, usize) -> T,
+    {
+        let mut current: Option<(i32, Arc<Year>)> = None;
+        dates
+            .iter()
+            .map(|date| {
+                let (y, n) = locate(date);
+                match current {
+                    Some((cached, ref year)) if cached == y => f(year, date, n),
+                    _ => {
+                        let year = self.year(y);
+                        let result = f(&year, date, n);
+                        current = Some((y, year));
+                        result
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// 指定年のテーブルで判定する
+    /// 計算済みであれば、読み込みロックの間に判定を済ませる
+    fn with_year<T, F: FnOnce(&Year) -> T>(&self, year: i32, f: F) -> T {
+        let years = self.years.read().expect("Poisoned holiday table!");
+        match years.get(&year) {
+            Some(found) => f(found),
+            None => {
+                drop(years);
+                f(&self.year(year))
+            }
+        }
+    }
+
+    /// 指定年のテーブルを返す
+    /// 未計算であれば計算して保持する
+    fn year(&self, year: i32) -> Arc<Year> {
+        if let Some(found) = self
+            .years
+            .read()
+            .expect("Poisoned holiday table!")
+            .get(&year)
+        {
+            return Arc::clone(found);
+        }
+
+        let built = Arc::new(Year::build(year));
+        let mut years = self.years.write().expect("Poisoned holiday table!");
+        Arc::clone(years.entry(year).or_insert(built))
+    }
+}
+
+impl HolidaySource for HolidayTable {
+    fn holiday(&self, date: &Date) -> Option<String> {
+        self.name(date)
+    }
+}
+
+impl Year {
+    /// 元日から大晦日まで規則を評価して、1年分のテーブルを生成する
+    fn build(year: i32) -> Self {
+        let mut bits = [0; WORDS];
+        let mut names = Vec::new();
+
+        let mut date = Date::from_ymd(year, 1, 1).ok();
+        let mut n = 0;
+        while let Some(d) = date.filter(|d| d.year() == year) {
+            if let Some(name) = holiday::holiday(&d) {
+                bits[n / 64] |= 1 << (n % 64);
+                names.push((n as u16, name));
+            }
+            date = d.tomorrow().ok();
+            n += 1;
+        }
+
+        Year { bits, names }
+    }
+
+    /// 通日が祝祭日かどうかを判定する
+    fn contains(&self, n: usize) -> bool {
+        self.bits[n / 64] & (1 << (n % 64)) != 0
+    }
+
+    /// 通日が祝祭日であればその名前を返す
+    fn name(&self, n: usize) -> Option<String> {
+        if !self.contains(n) {
+            return None;
+        }
+        self.names
+            .binary_search_by_key(&(n as u16), |&(d, _)| d)
+            .ok()
+            .map(|i| self.names[i].1.clone())
+    }
+}
+
+/// 年と、元日を0とした通日を返す
+fn locate(date: &Date) -> (i32, usize) {
+    let year = date.year();
+    let new_year = Date::from_ymd(year, 1, 1).expect("Invalid new year's day!");
+    (year, date.num_days(&new_year) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Calendar;
+
+    #[test]
+    fn agree_with_rules() {
+        let table = HolidayTable::new();
+        let cal = Calendar::build()
+            .from("1946-01")
+            .until("2030-12")
+            .finalize()
+            .unwrap();
+
+        for date in cal.iter() {
+            assert_eq!(table.name(&date), holiday::holiday(&date), "{}", date);
+            assert_eq!(table.is_business_day(&date), date.is_business_day());
+        }
+        assert_eq!(table.years().len(), 85);
+    }
+
+    #[test]
+    fn batch_across_years() {
+        let dates = Calendar::build()
+            .from("2018-12")
+            .until("2019-01")
+            .finalize()
+            .unwrap()
+            .make();
+        let table = HolidayTable::new();
+
+        let names = table.names(&dates);
+        let flags = table.holidays(&dates);
+        let business = table.business_days(&dates);
+        assert_eq!(names.len(), dates.len());
+        for (i, date) in dates.iter().enumerate() {
+            assert_eq!(names[i], date.holiday());
+            assert_eq!(flags[i], date.holiday().is_some());
+            assert_eq!(business[i], date.is_business_day());
+        }
+        assert_eq!(table.years(), vec![2018, 2019]);
+    }
+
+    #[test]
+    fn leap_year_end() {
+        let table = HolidayTable::new();
+        let date = Date::from_ymd(2020, 12, 31).unwrap();
+        assert!(!table.is_holiday(&date));
+        assert_eq!(locate(&date), (2020, 365));
+    }
+
+    #[test]
+    fn last_supported_year() {
+        let table = HolidayTable::new();
+        assert!(!table.is_holiday(&Date::MAX));
+        assert!(!table.is_holiday(&Date::MIN));
+    }
+
+    #[test]
+    fn share_between_threads() {
+        let table = Arc::new(HolidayTable::new());
+        let handles = (2018..2022)
+            .map(|year| {
+                let table = Arc::clone(&table);
+                std::thread::spawn(move || {
+                    let date = Date::from_ymd(year, 1, 1).unwrap();
+                    table.is_holiday(&date)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for handle in handles {
+            assert!(handle.join().unwrap());
+        }
+        assert_eq!(table.years(), vec![2018, 2019, 2020, 2021]);
+    }
+}
@@ -0,0 +1,359 @@
+//! # 週番号
+//!
+//! ISO 8601 の週番号の定義。
+//! 週は月曜に始まり、その年の最初の木曜日を含む週を第1週とする。
+//! そのため、年始・年末の数日は前年・翌年の週に属することがある。
+#[cfg(feature = "serde")]
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use alloc::string::{String, ToString};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::date::nth_weekday;
+use crate::{is_leap, Calendar, Date, KoyomiError, KoyomiResult, Weekday};
+
+/// ISO 8601 の週
+///
+/// 週番号の年(暦年とは限らない)と週番号を持つ。
+/// `2025-W07` 形式の文字列と相互に変換できる。
+///
+/// # Examples
+///
+/// ```rust
+/// use koyomi::{Date, IsoWeek};
+///
+/// let date = Date::from_ymd(2024, 12, 30).unwrap();
+/// let week = date.iso_week();
+/// assert_eq!((week.year(), week.week()), (2025, 1));
+/// assert_eq!(week.to_string(), "2025-W01");
+///
+/// let week: IsoWeek = "2025-W07".parse().unwrap();
+/// assert_eq!(week.first_day().to_string(), "2025-02-10");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Week"))]
+pub struct IsoWeek {
+    year: i32,
+    week: u32,
+}
+
+/// デシリアライズ時に週番号を検証するための中間表現
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct Week {
+    year: i32,
+    week: u32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Week> for IsoWeek {
+    type Error = String;
+
+    fn try_from(week: Week) -> Result<Self, Self::Error> {
+        IsoWeek::new(week.year, week.week).map_err(|e| e.to_string())
+    }
+}
+
+impl IsoWeek {
+    /// 週番号の年と週番号から生成する
+    /// 存在しない週番号はエラーとなる
+    /// 扱える範囲の端の週は、範囲内の日を含む限り生成できる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::IsoWeek;
+    ///
+    /// assert!(IsoWeek::new(2020, 53).is_ok());
+    /// assert!(IsoWeek::new(2021, 53).is_err());
+    /// assert!(IsoWeek::new(2021, 0).is_err());
+    /// ```
+    pub fn new(year: i32, week: u32) -> KoyomiResult<Self> {
+        let weeks = match IsoWeek::num_weeks(year) {
+            Ok(weeks) => weeks,
+            // 扱える最初・最後の日は、範囲外の年の週に属することがある
+            Err(e) => {
+                return [Date::MIN, Date::MAX]
+                    .iter()
+                    .map(IsoWeek::of)
+                    .find(|w| w.year == year && w.week == week)
+                    .ok_or(e)
+            }
+        };
+        if week == 0 || week > weeks {
+            return Err(KoyomiError::InvalidWeek(year, week));
+        }
+        Ok(IsoWeek { year, week })
+    }
+
+    /// 日付が属する週を導出する
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{Date, IsoWeek};
+    ///
+    /// let date = Date::from_ymd(2021, 1, 3).unwrap();
+    /// let week = IsoWeek::of(&date);
+    /// assert_eq!((week.year(), week.week()), (2020, 53));
+    /// ```
+    pub fn of(date: &Date) -> Self {
+        let year = date.year();
+        let new_year = Date::from_ymd(year, 1, 1).expect("Invalid new year's day!");
+        let ordinal = date.num_days(&new_year) as u32 + 1;
+        // 同じ週の木曜日が、年の何日目にあたるかで週番号が決まる
+        let week = (ordinal + 10 - date.weekday().number_from_monday()) / 7;
+
+        if week == 0 {
+            let last = Date::from_ymd(year - 1, 1, 1).expect("Invalid new year's day!");
+            IsoWeek {
+                year: year - 1,
+                week: weeks_in(&last),
+            }
+        } else if week > weeks_in(&new_year) {
+            IsoWeek {
+                year: year + 1,
+                week: 1,
+            }
+        } else {
+            IsoWeek { year, week }
+        }
+    }
+
+    /// 指定年の週の数(52または53)を返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::IsoWeek;
+    ///
+    /// assert_eq!(IsoWeek::num_weeks(2020).unwrap(), 53);
+    /// assert_eq!(IsoWeek::num_weeks(2025).unwrap(), 52);
+    /// ```
+    pub fn num_weeks(year: i32) -> KoyomiResult<u32> {
+        Date::from_ymd(year, 1, 1).map(|d| weeks_in(&d))
+    }
+
+    /// 週番号の年を返す
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// 週番号(1〜53)を返す
+    pub fn week(&self) -> u32 {
+        self.week
+    }
+
+    /// 週の指定した曜日の日付を返す
+    /// 扱える範囲外の日付となる場合はエラーとなる
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::{IsoWeek, Weekday};
+    ///
+    /// let week = IsoWeek::new(2025, 7).unwrap();
+    /// let date = week.day(Weekday::Wednesday).unwrap();
+    /// assert_eq!(date.to_string(), "2025-02-12");
+    /// ```
+    pub fn day(&self, weekday: Weekday) -> KoyomiResult<Date> {
+        // 1月4日を含む週が第1週となる
+        let jan4 = Date::from_ymd(self.year, 1, 4)?;
+        let offset = i64::from(weekday.num_days_from_monday())
+            - i64::from(jan4.weekday().num_days_from_monday());
+        jan4.shift(i64::from(self.week - 1) * 7 + offset)
+    }
+
+    /// 週の初日(月曜)を返す
+    /// 扱える範囲の端の週では、範囲内の最初の日となる
+    pub fn first_day(&self) -> Date {
+        self.day(Weekday::Monday).unwrap_or(Date::MIN)
+    }
+
+    /// 週の末日(日曜)を返す
+    /// 扱える範囲の端の週では、範囲内の最後の日となる
+    pub fn last_day(&self) -> Date {
+        self.day(Weekday::Sunday).unwrap_or(Date::MAX)
+    }
+
+    /// 週の期間のカレンダーを返す
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use koyomi::IsoWeek;
+    ///
+    /// let cal = IsoWeek::new(2025, 1).unwrap().calendar();
+    /// assert_eq!(cal.from(), "2024-12-30");
+    /// assert_eq!(cal.until(), "2025-01-05");
+    /// ```
+    pub fn calendar(&self) -> Calendar {
+        Calendar::new(self.first_day(), self.last_day()).expect("Invalid week term!")
+    }
+}
+
+impl fmt::Display for IsoWeek {
+    /// `YYYY-Www` 形式で表示する
+    /// 年は `Date` と同じく4桁以上に0埋めする
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.year < 0 { "-" } else { "" };
+        write!(
+            f,
+            "{}{:04}-W{:02}",
+            sign,
+            self.year.unsigned_abs(),
+            self.week
+        )
+    }
+}
+
+impl FromStr for IsoWeek {
+    type Err = KoyomiError;
+
+    /// `YYYY-Www` 形式の文字列から生成する
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, week) = parse_week(s)?;
+        IsoWeek::new(year, week)
+    }
+}
+
+/// `YYYY-Www` 形式の文字列を、年と週番号に分解する
+/// 週番号の範囲は検証しない
+pub(crate) fn parse_week(s: &str) -> KoyomiResult<(i32, u32)> {
+    let invalid = || KoyomiError::InvalidFormat(s.into());
+    let (year, week) = s.rsplit_once("-W").ok_or_else(invalid)?;
+    let digits = year.strip_prefix(['-', '+']).unwrap_or(year);
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    if week.len() != 2 || !week.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+
+    let year = year.parse().map_err(|_| invalid())?;
+    let week = week.parse().map_err(|_| invalid())?;
+    Ok((year, week))
+}
+
+/// `YYYY-Www-D` 形式の文字列から日付を生成する
+/// 曜日は月曜を1、日曜を7とする
+pub(crate) fn parse_week_date(s: &str) -> KoyomiResult<Date> {
+    let invalid = || KoyomiError::InvalidFormat(s.into());
+    let (week, day) = s.rsplit_once('-').ok_or_else(invalid)?;
+    let day = match day.as_bytes() {
+        [d @ b'1'..=b'7'] => i64::from(d - b'1'),
+        _ => return Err(invalid()),
+    };
+    week.parse::<IsoWeek>()?.day(nth_weekday(day))
+}
+
+/// 1月1日の曜日から、その年の週の数を求める
+/// 1月1日が木曜の年と、水曜のうるう年は53週となる
+fn weeks_in(new_year: &Date) -> u32 {
+    match new_year.weekday() {
+        Weekday::Thursday => 53,
+        Weekday::Wednesday if is_leap(new_year.year()) => 53,
+        _ => 52,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn week_of_year_boundaries() {
+        let cases = [
+            ((2018, 12, 31), (2019, 1)),
+            ((2020, 12, 31), (2020, 53)),
+            ((2021, 1, 3), (2020, 53)),
+            ((2021, 1, 4), (2021, 1)),
+            ((2024, 12, 29), (2024, 52)),
+            ((2024, 12, 30), (2025, 1)),
+            ((2026, 12, 31), (2026, 53)),
+            ((2027, 1, 1), (2026, 53)),
+        ];
+        for &((y, m, d), (year, week)) in cases.iter() {
+            let iso = Date::from_ymd(y, m, d).unwrap().iso_week();
+            assert_eq!((iso.year(), iso.week()), (year, week), "{}-{}-{}", y, m, d);
+        }
+    }
+
+    #[test]
+    fn days_of_week() {
+        let mut date = Date::from_ymd(2015, 12, 28).unwrap();
+        while date.year() < 2030 {
+            let iso = date.iso_week();
+            assert_eq!(iso.day(date.weekday()).unwrap(), date);
+            assert!(iso.first_day() <= date && date <= iso.last_day());
+            assert_eq!(IsoWeek::new(iso.year(), iso.week()).unwrap(), iso);
+            date = date.tomorrow().unwrap();
+        }
+    }
+
+    #[test]
+    fn parse_and_display() {
+        let week = "2025-W07".parse::<IsoWeek>().unwrap();
+        assert_eq!(week, IsoWeek::new(2025, 7).unwrap());
+        assert_eq!(week.to_string(), "2025-W07");
+
+        let week = Date::from_ymd(-44, 3, 15).unwrap().iso_week();
+        assert_eq!(week.to_string(), "-0044-W11");
+        assert_eq!(week.to_string().parse::<IsoWeek>().unwrap(), week);
+
+        let invalid = [
+            "2025-W7",
+            "2025-W007",
+            "2025W07",
+            "2025-w07",
+            "W07",
+            "+-2025-W07",
+        ];
+        for s in invalid.iter() {
+            assert!(s.parse::<IsoWeek>().is_err(), "{}", s);
+        }
+        let err = "2021-W53".parse::<IsoWeek>().unwrap_err();
+        assert_eq!(err.to_string(), "2021年に第53週はありません");
+    }
+
+    #[test]
+    fn parse_week_date() {
+        let date = Date::parse("2025-W07-3").unwrap();
+        assert_eq!(date.to_string(), "2025-02-12");
+        assert_eq!(Date::parse("2020-W53-7").unwrap().to_string(), "2021-01-03");
+        assert_eq!(Date::parse("2025-W01-1").unwrap().to_string(), "2024-12-30");
+
+        assert!(Date::parse("2025-W07-0").is_err());
+        assert!(Date::parse("2025-W07-8").is_err());
+        assert!(Date::parse("2025-W07").is_err());
+        assert!(Date::parse("2025-W54-1").is_err());
+    }
+
+    #[test]
+    fn out_of_range() {
+        let first = Date::MIN.iso_week();
+        assert_eq!((first.year(), first.week()), (-262_143, 1));
+        assert!(first.day(Weekday::Monday).is_err());
+        assert_eq!(first.first_day(), Date::MIN);
+
+        let last = Date::MAX.iso_week();
+        assert_eq!((last.year(), last.week()), (262_143, 1));
+        assert!(last.day(Weekday::Tuesday).is_err());
+        assert_eq!(last.calendar().until(), Date::MAX.to_string());
+
+        // 範囲の端の週も、生成と文字列との変換ができる
+        for week in [first, last].iter() {
+            assert_eq!(IsoWeek::new(week.year(), week.week()).unwrap(), *week);
+            assert_eq!(week.to_string().parse::<IsoWeek>().unwrap(), *week);
+        }
+        assert_eq!(last.to_string(), "262143-W01");
+        assert!(IsoWeek::new(262_143, 2).is_err());
+        assert!(IsoWeek::new(-262_144, 52).is_err());
+    }
+}
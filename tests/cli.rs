@@ -0,0 +1,152 @@
+use std::process::Command;
+
+fn koyomi(args: &[&str]) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_koyomi"))
+        .args(args)
+        .output()
+        .expect("Failed to execute koyomi");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    (output.status.success(), stdout)
+}
+
+#[test]
+fn cal_month() {
+    let (ok, out) = koyomi(&["cal", "2025-05", "--plain"]);
+    assert!(ok);
+    assert_eq!(out.lines().next().unwrap(), "    令和7年 5月");
+    assert_eq!(out.lines().nth(1).unwrap(), "日 月 火 水 木 金 土");
+}
+
+#[test]
+fn cal_three_months() {
+    let (ok, out) = koyomi(&["cal", "2025-01", "-3", "--plain"]);
+    assert!(ok);
+    let title = out.lines().next().unwrap();
+    assert!(title.contains("令和6年 12月"));
+    assert!(title.contains("令和7年 2月"));
+}
+
+#[test]
+fn cal_json() {
+    let (ok, out) = koyomi(&["cal", "2019-05", "--json"]);
+    assert!(ok);
+    assert!(out.starts_with("[{\"date\":\"2019-05-01\",\"weekday\":\"水\",\"era\":\"令和\""));
+    assert_eq!(out.matches("\"date\"").count(), 31);
+}
+
+#[test]
+fn date_text() {
+    let (ok, out) = koyomi(&["date", "2019-05-01"]);
+    assert!(ok);
+    assert_eq!(
+        out,
+        "2019-05-01 (水)\n和暦: 令和元年5月1日\n祝日: 新天皇即位日\n"
+    );
+}
+
+#[test]
+fn date_json() {
+    let (ok, out) = koyomi(&["date", "2018-12-25", "--json"]);
+    assert!(ok);
+    assert_eq!(
+        out,
+        "{\"date\":\"2018-12-25\",\"weekday\":\"火\",\"era\":\"平成\",\"era_year\":30,\"wareki\":\"平成30年12月25日\",\"holiday\":null,\"business_day\":true}\n"
+    );
+}
+
+#[test]
+fn holidays_text() {
+    let (ok, out) = koyomi(&["holidays", "2018"]);
+    assert!(ok);
+    assert_eq!(out.lines().count(), 20);
+    assert_eq!(out.lines().next().unwrap(), "2018-01-01 (月) 元日");
+}
+
+#[test]
+fn convert_both_ways() {
+    let (ok, out) = koyomi(&["convert", "令和2年5月1日"]);
+    assert!(ok);
+    assert_eq!(out, "2020-05-01\n");
+
+    let (ok, out) = koyomi(&["convert", "2019-05-01"]);
+    assert!(ok);
+    assert_eq!(out, "令和元年5月1日\n");
+
+    let (ok, out) = koyomi(&["convert", "平成元年1月8日", "--json"]);
+    assert!(ok);
+    assert_eq!(
+        out,
+        "{\"date\":\"1989-01-08\",\"wareki\":\"平成元年1月8日\"}\n"
+    );
+
+    let (ok, _) = koyomi(&["convert", "平成31年5月1日"]);
+    assert!(!ok);
+}
+
+#[test]
+fn convert_kanji_and_full_width() {
+    let (ok, out) = koyomi(&["convert", "2025年5月1日(木)"]);
+    assert!(ok);
+    assert_eq!(out, "令和7年5月1日\n");
+
+    let (ok, out) = koyomi(&["convert", "令和７年５月１日"]);
+    assert!(ok);
+    assert_eq!(out, "2025-05-01\n");
+
+    let (ok, out) = koyomi(&["date", "２０１９-０５-０１"]);
+    assert!(ok);
+    assert!(out.starts_with("2019-05-01 (水)\n"));
+}
+
+#[test]
+fn business_days() {
+    let (ok, out) = koyomi(&["business-days", "2018-05"]);
+    assert!(ok);
+    assert_eq!(out, "21\n");
+
+    let (ok, out) = koyomi(&["business-days", "2018-04", "2018-05", "--json"]);
+    assert!(ok);
+    assert_eq!(
+        out,
+        "{\"from\":\"2018-04-01\",\"until\":\"2018-05-31\",\"business_days\":41}\n"
+    );
+}
+
+#[test]
+fn invalid_usage() {
+    let (ok, _) = koyomi(&[]);
+    assert!(!ok);
+
+    let (ok, _) = koyomi(&["unknown"]);
+    assert!(!ok);
+
+    let (ok, _) = koyomi(&["date", "--unknown"]);
+    assert!(!ok);
+}
+
+#[test]
+fn error_message() {
+    let output = Command::new(env!("CARGO_BIN_EXE_koyomi"))
+        .args(["date", "2019-02-29"])
+        .output()
+        .expect("Failed to execute koyomi");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(!output.status.success());
+    assert_eq!(stderr, "koyomi: 2019年2月29日は存在しません\n");
+}
+
+#[test]
+fn cal_beyond_range() {
+    let (ok, out) = koyomi(&["cal", "262142-11", "--plain"]);
+    assert!(ok);
+    assert!(out.lines().next().unwrap().ends_with("年 11月"));
+
+    // 12月は翌月で埋める日付が範囲外となるため、パニックせずにエラーとなる
+    let output = Command::new(env!("CARGO_BIN_EXE_koyomi"))
+        .args(["cal", "262142", "--plain"])
+        .output()
+        .expect("Failed to execute koyomi");
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr.starts_with("koyomi: "));
+}